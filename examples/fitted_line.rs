@@ -1,54 +1,58 @@
 use pgfplots::{
-    axis::{plot::*, *},
-    Engine, Picture,
+    document::{
+        tikzpicture::axis::{
+            legend::LegendPos,
+            plot::bidimensional::{ErrorCharacter, ErrorDirection, Plot2D, PlotOption, Type2D},
+            Axis, AxisOption,
+        },
+        Document,
+    },
+    engine::LatexEngine,
 };
 use std::f64::consts::PI;
 
 fn main() {
     // Set the straight dotted line
-    let mut line = Plot2D::new();
-    line.coordinates = (0..11)
-        .into_iter()
-        .map(|i| (f64::from(i), 2.0 * PI * f64::from(i)).into())
-        .collect();
-    line.add_key(PlotKey::Custom(String::from("dashed")));
+    let line = Plot2D::new()
+        .coordinates(
+            (0..11)
+                .map(|i| (f64::from(i), 2.0 * PI * f64::from(i)).into())
+                .collect::<Vec<_>>(),
+        )
+        .option(PlotOption::Custom(String::from("dashed")))
+        .legend("fit");
 
     // Set the points with error bars
-    let mut points = Plot2D::new();
-    points
-        .coordinates
-        .push((1.0, 8.0, Some(0.2), Some(0.9)).into());
-    points
-        .coordinates
-        .push((3.0, 16.0, Some(0.4), Some(1.4)).into());
-    points
-        .coordinates
-        .push((5.0, 33.0, Some(0.2), Some(3.4)).into());
-    points
-        .coordinates
-        .push((7.0, 41.0, Some(0.2), Some(3.4)).into());
-    points
-        .coordinates
-        .push((9.0, 58.0, Some(0.5), Some(1.4)).into());
-    points.add_key(PlotKey::Type2D(Type2D::OnlyMarks));
-    points.add_key(PlotKey::XError(ErrorCharacter::Absolute));
-    points.add_key(PlotKey::XErrorDirection(ErrorDirection::Both));
-    points.add_key(PlotKey::YError(ErrorCharacter::Absolute));
-    points.add_key(PlotKey::YErrorDirection(ErrorDirection::Both));
-    points.add_key(PlotKey::Custom(String::from("mark size=1pt")));
+    let points = Plot2D::new()
+        .coordinate((1.0, 8.0, Some(0.2), Some(0.9)))
+        .coordinate((3.0, 16.0, Some(0.4), Some(1.4)))
+        .coordinate((5.0, 33.0, Some(0.2), Some(3.4)))
+        .coordinate((7.0, 41.0, Some(0.2), Some(3.4)))
+        .coordinate((9.0, 58.0, Some(0.5), Some(1.4)))
+        .option(PlotOption::Type2D(Type2D::OnlyMarks))
+        .option(PlotOption::XError(ErrorCharacter::Absolute))
+        .option(PlotOption::XErrorDirection(ErrorDirection::Both))
+        .option(PlotOption::YError(ErrorCharacter::Absolute))
+        .option(PlotOption::YErrorDirection(ErrorDirection::Both))
+        .option(PlotOption::Custom(String::from("mark size=1pt")))
+        .legend("data");
 
     // Customize axis environment
-    let mut axis = Axis::new();
-    axis.set_title("Slope is $2\\pi$");
-    axis.set_x_label("Radius~[m]");
-    axis.set_y_label("Circumference~[m]");
-    axis.plots.push(line);
-    axis.plots.push(points);
-    axis.add_key(AxisKey::Custom(String::from("legend entries={fit,data}")));
-    axis.add_key(AxisKey::Custom(String::from("legend pos=north west")));
+    let axis = Axis::new()
+        .title("Slope is $2\\pi$")
+        .x_label("Radius~[m]")
+        .y_label("Circumference~[m]")
+        .option(AxisOption::LegendPos(LegendPos::NorthWest))
+        .plot(line.into())
+        .plot(points.into());
 
     #[cfg(feature = "tectonic")]
-    Picture::from(axis).show_pdf(Engine::Tectonic).unwrap();
+    let engine = LatexEngine::Tectonic;
     #[cfg(not(feature = "tectonic"))]
-    Picture::from(axis).show_pdf(Engine::PdfLatex).unwrap();
+    let engine = LatexEngine::PdfLatex;
+
+    Document::from(axis)
+        .pdf(engine)
+        .and_then(|pdf| pdf.open())
+        .unwrap();
 }
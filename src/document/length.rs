@@ -0,0 +1,127 @@
+use super::preamble::PgfPlotsCompat;
+use std::{fmt, ops::Neg};
+
+/// TeX dimension, used by every option controlling a size, a distance or a shift.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::length::Length;
+///
+/// assert_eq!("2.5mm", Length::Mm(2.5).to_string());
+/// assert_eq!("0.5\\linewidth", Length::LineWidth(0.5).to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Length {
+    /// Length in points (`pt`).
+    Pt(f64),
+    /// Length in millimeters (`mm`).
+    Mm(f64),
+    /// Length in centimeters (`cm`).
+    Cm(f64),
+    /// Length in inches (`in`).
+    In(f64),
+    /// Length relative to the width of the `M` of the current font (`em`).
+    Em(f64),
+    /// Length relative to the height of the `x` of the current font (`ex`).
+    Ex(f64),
+    /// Fraction of the width of the lines of the surrounding text (`\linewidth`).
+    LineWidth(f64),
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pt(value) => write!(f, "{value}pt"),
+            Self::Mm(value) => write!(f, "{value}mm"),
+            Self::Cm(value) => write!(f, "{value}cm"),
+            Self::In(value) => write!(f, "{value}in"),
+            Self::Em(value) => write!(f, "{value}em"),
+            Self::Ex(value) => write!(f, "{value}ex"),
+            Self::LineWidth(value) => write!(f, "{value}\\linewidth"),
+        }
+    }
+}
+
+impl Neg for Length {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self {
+            Self::Pt(value) => Self::Pt(-value),
+            Self::Mm(value) => Self::Mm(-value),
+            Self::Cm(value) => Self::Cm(-value),
+            Self::In(value) => Self::In(-value),
+            Self::Em(value) => Self::Em(-value),
+            Self::Ex(value) => Self::Ex(-value),
+            Self::LineWidth(value) => Self::LineWidth(-value),
+        }
+    }
+}
+
+/// Width or shift of the bars of a
+/// [`crate::document::tikzpicture::axis::plot::bidimensional::Type2D::YBar`] plot, either a
+/// TeX dimension or a length in axis units.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::length::{BarLength, Length};
+///
+/// assert_eq!("10pt", BarLength::from(Length::Pt(10.0)).to_string());
+/// assert_eq!("0.5", BarLength::Axis(0.5).to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BarLength {
+    /// TeX dimension.
+    Length(Length),
+    /// Length in axis units, i.e. relative to the axis coordinates, which requires at least
+    /// [`PgfPlotsCompat::V1_7`].
+    Axis(f64),
+}
+
+impl fmt::Display for BarLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(length) => write!(f, "{length}"),
+            // Unitless bar lengths are interpreted in axis units since `compat=1.7`.
+            Self::Axis(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<Length> for BarLength {
+    fn from(length: Length) -> Self {
+        Self::Length(length)
+    }
+}
+
+impl BarLength {
+    /// Returns the minimum PGFPlots compatibility layer required to interpret the length.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        match self {
+            Self::Length(_) => PgfPlotsCompat::Default,
+            Self::Axis(_) => PgfPlotsCompat::V1_7,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn length() {
+        assert_eq!("-3pt", (-Length::Pt(3.0)).to_string());
+        assert_eq!("1in", Length::In(1.0).to_string());
+        assert_eq!("0.5", BarLength::Axis(0.5).to_string());
+        assert_eq!(
+            PgfPlotsCompat::V1_7,
+            BarLength::Axis(0.5).required_pgfcompat()
+        );
+        assert_eq!(
+            PgfPlotsCompat::Default,
+            BarLength::from(Length::Em(1.0)).required_pgfcompat()
+        );
+    }
+}
@@ -1,17 +1,36 @@
+pub mod length;
 pub mod preamble;
 pub mod tikzpicture;
 
-use crate::{engine::LatexEngine, output::LatexOutput, Result};
-use preamble::{Package, PgfPlotsCompat, PgfPlotsLib, Preamble};
+use crate::{
+    engine::LatexEngine,
+    error::PreambleError,
+    output::{LatexOutput, LatexOutputSaveError, OutputMode},
+    Result,
+};
+use preamble::{
+    ColorStop, ColormapRef, CycleListRef, DocumentClass, EveryStyle, Fonts, Metadata, Package,
+    PgfPlotsCompat, PgfPlotsLib, Placement, Preamble, PreambleItem, Requirements, StandaloneOption,
+    StyleRef, TikzLib,
+};
+use std::{fs, path::Path};
 use tikzpicture::{axis::Axis, TikzPicture};
 
-/// Standalone LaTeX document used to generate the plot.
+/// LaTeX document used to generate the plot.
+///
+/// By default the document uses the `standalone` class, producing a cropped figure;
+/// see [`Document::document_class`] to produce full pages or slides instead.
 #[derive(Debug, Clone, Default)]
 pub struct Document {
     /// LaTeX document *preamble*.
     preamble: Preamble,
     /// LaTeX document *body* (*pictures*).
     body: Vec<TikzPicture>,
+    /// Layout of the output when the document contains multiple pictures.
+    output_mode: OutputMode,
+    /// Pinned build date, as seconds since the Unix epoch: if [`Some`] the output is
+    /// reproducible.
+    source_date_epoch: Option<u64>,
 }
 
 impl Document {
@@ -20,6 +39,87 @@ impl Document {
         Self::default()
     }
 
+    /// Set the LaTeX document class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::{
+    ///     preamble::{ArticleOption, DocumentClass, PaperSize},
+    ///     Document,
+    /// };
+    ///
+    /// let document = Document::new()
+    ///     .document_class(DocumentClass::Article(vec![ArticleOption::PaperSize(PaperSize::A4)]));
+    /// ```
+    pub fn document_class(mut self, class: DocumentClass) -> Self {
+        self.preamble.set_document_class(class);
+        self
+    }
+
+    /// Set the typography configuration: the fonts are loaded according to the
+    /// [`LatexEngine`] used to compile the document.
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.preamble.set_fonts(fonts);
+        self
+    }
+
+    /// Set the [`OutputMode`], i.e. the layout of the output when the document contains
+    /// multiple pictures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{
+    ///     document::{tikzpicture::TikzPicture, Document},
+    ///     output::OutputMode,
+    /// };
+    ///
+    /// let document = Document::new()
+    ///     .output_mode(OutputMode::PagePerPicture)
+    ///     .picture(TikzPicture::new())
+    ///     .picture(TikzPicture::new());
+    /// assert!(document
+    ///     .standalone_string().unwrap()
+    ///     .starts_with(r"\documentclass[multi=tikzpicture]{standalone}"));
+    /// ```
+    pub fn output_mode(mut self, output_mode: OutputMode) -> Self {
+        self.output_mode = output_mode;
+        self
+    }
+
+    /// Set the PDF document metadata (title, authors, subject and keywords), written through
+    /// the `hyperref` and `hyperxmp` packages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::{preamble::Metadata, Document};
+    ///
+    /// let document = Document::new().metadata(
+    ///     Metadata::new()
+    ///         .title("Convergence")
+    ///         .author("Jane Doe")
+    ///         .subject("Benchmark results"),
+    /// );
+    /// ```
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.preamble.set_metadata(metadata);
+        self
+    }
+
+    /// Make the PDF output reproducible: compiling the same document always produces the
+    /// same bytes.
+    ///
+    /// The creation and modification dates are pinned to `source_date_epoch` (seconds since
+    /// the Unix epoch) through the `SOURCE_DATE_EPOCH` environment variable, and the
+    /// information changing at each compilation (e.g. the PDF trailer ID) is suppressed
+    /// according to the [`LatexEngine`].
+    pub fn reproducible(mut self, source_date_epoch: u64) -> Self {
+        self.set_reproducible(source_date_epoch);
+        self
+    }
+
     /// Set PGFPlots compatibility layer.
     pub fn pgfcompat(mut self, pgfcompat: PgfPlotsCompat) -> Self {
         self.preamble.set_pgfcompat(pgfcompat);
@@ -32,7 +132,7 @@ impl Document {
         Ok(self)
     }
 
-    /// Add a PGFPlots library to the document preamble.
+    /// Add a PGFPlots library to the document preamble, unless already present.
     pub fn pgflib<L>(mut self, lib: L) -> Self
     where
         L: Into<PgfPlotsLib>,
@@ -47,32 +147,119 @@ impl Document {
         self
     }
 
+    /// Add a Ti*k*Z library to the document preamble, unless already present.
+    pub fn tikzlib<L>(mut self, lib: L) -> Self
+    where
+        L: Into<TikzLib>,
+    {
+        self.preamble.add_tikzlib(lib.into());
+        self
+    }
+
+    /// Add Ti*k*Z libraries to the document preamble.
+    pub fn tikzlibs(mut self, libs: &[TikzLib]) -> Self {
+        self.preamble.add_tikzlibs(libs);
+        self
+    }
+
     /// Add a LaTeX package to the document preamble.
-    pub fn pkg<P>(mut self, pkg: P) -> Self
+    ///
+    /// If the package is already present, its options are merged with the existing ones.
+    /// Returns an error if the package has already been loaded with conflicting options.
+    pub fn pkg<P>(mut self, pkg: P) -> Result<Self>
     where
         P: Into<Package>,
     {
-        self.preamble.add_pkg(pkg.into());
-        self
+        self.preamble.add_pkg(pkg.into())?;
+        Ok(self)
     }
 
     /// Add LaTeX packages to the document preamble.
-    pub fn pkgs(mut self, pkgs: &[Package]) -> Self {
-        self.preamble.add_pkgs(pkgs);
-        self
+    ///
+    /// If a package is already present, its options are merged with the existing ones.
+    /// Returns an error if a package has already been loaded with conflicting options.
+    pub fn pkgs(mut self, pkgs: &[Package]) -> Result<Self> {
+        self.preamble.add_pkgs(pkgs)?;
+        Ok(self)
+    }
+
+    /// Add a [`PreambleItem`] (macro, color definition or raw line) to the document preamble,
+    /// after the packages.
+    ///
+    /// Returns an error if the item is malformed (e.g. a macro with more than 9 arguments).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::{preamble::PreambleItem, Document};
+    ///
+    /// let document = Document::new()
+    ///     .item(PreambleItem::MathOperator {
+    ///         name: "argmax".to_string(),
+    ///         operator: r"arg\,max".to_string(),
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn item(mut self, item: PreambleItem) -> Result<Self> {
+        self.add_item(item)?;
+        Ok(self)
+    }
+
+    /// Add a [`PreambleItem`] to the document preamble, at the given [`Placement`].
+    ///
+    /// Returns an error if the item is malformed (e.g. a macro with more than 9 arguments).
+    pub fn item_at(mut self, item: PreambleItem, placement: Placement) -> Result<Self> {
+        self.add_item_at(item, placement)?;
+        Ok(self)
     }
 
     /// Add a [`TikzPicture`] (equivalent to the Ti*k*Z graphics environment) to the document.
+    ///
+    /// The PGFPlots and Ti*k*Z libraries required by the picture are added to the preamble, and
+    /// the PGFPlots compatibility layer is raised to the minimum version required by the
+    /// picture (unless explicitly set).
     pub fn picture<P>(mut self, tikzpicture: P) -> Self
     where
         P: Into<TikzPicture>,
     {
         let tikzpicture = tikzpicture.into();
         self.add_pgflibs(&tikzpicture.required_libs());
+        self.add_tikzlibs(&tikzpicture.required_tikzlibs());
+        self.preamble
+            .require_pgfcompat(tikzpicture.required_pgfcompat());
         self.body.push(tikzpicture);
         self
     }
 
+    /// Set the LaTeX document class.
+    pub fn set_document_class(&mut self, class: DocumentClass) {
+        self.preamble.set_document_class(class);
+    }
+
+    /// Set the [`OutputMode`], i.e. the layout of the output when the document contains
+    /// multiple pictures.
+    pub fn set_output_mode(&mut self, output_mode: OutputMode) {
+        self.output_mode = output_mode;
+    }
+
+    /// Set the PDF document metadata (title, authors, subject and keywords), written through
+    /// the `hyperref` and `hyperxmp` packages.
+    pub fn set_metadata(&mut self, metadata: Metadata) {
+        self.preamble.set_metadata(metadata);
+    }
+
+    /// Make the PDF output reproducible: see [`Document::reproducible`].
+    pub fn set_reproducible(&mut self, source_date_epoch: u64) {
+        self.source_date_epoch = Some(source_date_epoch);
+        self.preamble.set_reproducible(true);
+    }
+
+    /// Set the typography configuration: the fonts are loaded according to the
+    /// [`LatexEngine`] used to compile the document.
+    pub fn set_fonts(&mut self, fonts: Fonts) {
+        self.preamble.set_fonts(fonts);
+    }
+
     /// Set PGFPlots compatibility layer.
     pub fn set_pgfcompat<C>(&mut self, pgfcompat: C)
     where
@@ -88,7 +275,7 @@ impl Document {
         Ok(())
     }
 
-    /// Add a PGFPlots library to the document preamble.
+    /// Add a PGFPlots library to the document preamble, unless already present.
     pub fn add_pgflib<L>(&mut self, lib: L)
     where
         L: Into<PgfPlotsLib>,
@@ -101,30 +288,162 @@ impl Document {
         self.preamble.add_pgflibs(libs);
     }
 
+    /// Add a Ti*k*Z library to the document preamble, unless already present.
+    pub fn add_tikzlib<L>(&mut self, lib: L)
+    where
+        L: Into<TikzLib>,
+    {
+        self.preamble.add_tikzlib(lib.into());
+    }
+
+    /// Add Ti*k*Z libraries to the document preamble.
+    pub fn add_tikzlibs(&mut self, libs: &[TikzLib]) {
+        self.preamble.add_tikzlibs(libs);
+    }
+
     /// Add a LaTeX package to the document preamble.
-    pub fn add_pkg<P>(&mut self, pkg: P)
+    ///
+    /// If the package is already present, its options are merged with the existing ones.
+    /// Returns an error if the package has already been loaded with conflicting options.
+    pub fn add_pkg<P>(&mut self, pkg: P) -> Result<()>
     where
         P: Into<Package>,
     {
-        self.preamble.add_pkg(pkg.into());
+        self.preamble.add_pkg(pkg.into())?;
+
+        Ok(())
     }
 
     /// Add LaTeX packages to the document preamble.
-    pub fn add_pkgs(&mut self, pkgs: &[Package]) {
-        self.preamble.add_pkgs(pkgs);
+    ///
+    /// If a package is already present, its options are merged with the existing ones.
+    /// Returns an error if a package has already been loaded with conflicting options.
+    pub fn add_pkgs(&mut self, pkgs: &[Package]) -> Result<()> {
+        self.preamble.add_pkgs(pkgs)?;
+
+        Ok(())
+    }
+
+    /// Add a [`PreambleItem`] (macro, color definition or raw line) to the document preamble,
+    /// after the packages.
+    ///
+    /// Returns an error if the item is malformed (e.g. a macro with more than 9 arguments).
+    pub fn add_item(&mut self, item: PreambleItem) -> Result<()> {
+        self.preamble.add_item(item)?;
+
+        Ok(())
+    }
+
+    /// Add a [`PreambleItem`] to the document preamble, at the given [`Placement`].
+    ///
+    /// Returns an error if the item is malformed (e.g. a macro with more than 9 arguments).
+    pub fn add_item_at(&mut self, item: PreambleItem, placement: Placement) -> Result<()> {
+        self.preamble.add_item_at(item, placement)?;
+
+        Ok(())
+    }
+
+    /// Register a named PGFPlots style in the document preamble, replacing any style
+    /// previously registered with the same name.
+    ///
+    /// Returns a [`StyleRef`] handle which can be used to apply the style to axes and plots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::{
+    ///     tikzpicture::axis::{Axis, AxisOption},
+    ///     Document,
+    /// };
+    ///
+    /// let mut document = Document::new();
+    /// let highlight = document.add_style("highlight", ["thick", "red"]);
+    ///
+    /// let axis = Axis::new().option(AxisOption::Style(highlight));
+    /// document.add_picture(axis);
+    /// ```
+    pub fn add_style<I>(&mut self, name: &str, options: I) -> StyleRef
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.preamble.add_style(name, options)
+    }
+
+    /// Append options to a predefined PGFPlots style (e.g. `every axis/.append style={...}`)
+    /// in the document preamble.
+    pub fn add_every_style<I>(&mut self, target: EveryStyle, options: I)
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.preamble.add_every_style(target, options);
+    }
+
+    /// Register a named cycle list in the document preamble, replacing any cycle list
+    /// previously registered with the same name.
+    ///
+    /// Returns a [`CycleListRef`] handle which can be used to apply the cycle list to axes.
+    pub fn add_cycle_list<I>(&mut self, name: &str, entries: I) -> CycleListRef
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.preamble.add_cycle_list(name, entries)
+    }
+
+    /// Register a named colormap in the document preamble, interpolating the given color
+    /// stops and replacing any colormap previously registered with the same name.
+    ///
+    /// The colormap is only defined: it does not replace the default colormap, and applies
+    /// only to the axes selecting it with [`tikzpicture::axis::colormap::Colormap::Registered`].
+    ///
+    /// Returns a [`ColormapRef`] handle which can be used to apply the colormap to axes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::{
+    ///     tikzpicture::axis::{colormap::Colormap, Axis, AxisOption},
+    ///     Document,
+    /// };
+    ///
+    /// let mut document = Document::new();
+    /// let heat = document.add_colormap("heat", [(0.0, [0, 0, 255]), (1.0, [255, 0, 0])]);
+    ///
+    /// let axis = Axis::new().option(AxisOption::Colormap(Colormap::Registered(heat)));
+    /// document.add_picture(axis);
+    /// ```
+    pub fn add_colormap<I>(&mut self, name: &str, stops: I) -> ColormapRef
+    where
+        I: IntoIterator,
+        I::Item: Into<ColorStop>,
+    {
+        self.preamble.add_colormap(name, stops)
     }
 
     /// Add a [`TikzPicture`] (equivalent to the Ti*k*Z graphics environment) to the document.
+    ///
+    /// The PGFPlots and Ti*k*Z libraries required by the picture are added to the preamble, and
+    /// the PGFPlots compatibility layer is raised to the minimum version required by the
+    /// picture (unless explicitly set).
     pub fn add_picture<P>(&mut self, tikzpicture: P)
     where
         P: Into<TikzPicture>,
     {
         let tikzpicture = tikzpicture.into();
         self.add_pgflibs(&tikzpicture.required_libs());
+        self.add_tikzlibs(&tikzpicture.required_tikzlibs());
+        self.preamble
+            .require_pgfcompat(tikzpicture.required_pgfcompat());
         self.body.push(tikzpicture);
     }
 
-    /// Return a [`String`] with valid LaTeX code that generates a standalone PDF.
+    /// Return a [`String`] with valid LaTeX code that generates the PDF document, using the
+    /// default [`LatexEngine`].
+    ///
+    /// The document contains all the pictures: with [`OutputMode::FilePerPicture`],
+    /// [`Document::standalone_strings`] returns the sources of the files actually compiled.
     ///
     /// # Note
     ///
@@ -143,38 +462,238 @@ impl Document {
     /// assert_eq!(
     /// r#"\documentclass{standalone}
     /// \usepackage{pgfplots}
+    /// \pgfplotsset{compat=default}
     ///
     /// \begin{document}
     /// \begin{tikzpicture}
     /// \end{tikzpicture}
     /// \end{document}"#,
-    /// document.standalone_string());
+    /// document.standalone_string().unwrap());
     /// ```
-    pub fn standalone_string(&self) -> String {
-        [
-            &self.preamble.to_string(),
-            r"\begin{document}",
-            &self
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the packages required by the fonts, metadata or preamble items
+    /// conflict with the ones added by the user.
+    pub fn standalone_string(&self) -> std::result::Result<String, PreambleError> {
+        self.latex_string(LatexEngine::default())
+    }
+
+    /// Return the [`String`]s with valid LaTeX code that generate the PDF documents, one per
+    /// output file according to the [`OutputMode`], using the default [`LatexEngine`].
+    ///
+    /// See [`Document::latex_strings`].
+    pub fn standalone_strings(&self) -> std::result::Result<Vec<String>, PreambleError> {
+        self.latex_strings(LatexEngine::default())
+    }
+
+    /// Return a [`String`] with valid LaTeX code that generates the PDF document when
+    /// compiled with the given [`LatexEngine`].
+    ///
+    /// The engine determines engine-specific parts of the preamble, e.g. the packages
+    /// loading the [`Fonts`].
+    ///
+    /// The document contains all the pictures: with [`OutputMode::FilePerPicture`],
+    /// [`Document::latex_strings`] returns the sources of the files actually compiled, one
+    /// per picture.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the packages required by the fonts, metadata or preamble items
+    /// conflict with the ones added by the user.
+    pub fn latex_string(&self, engine: LatexEngine) -> std::result::Result<String, PreambleError> {
+        match self.output_mode {
+            OutputMode::SinglePage | OutputMode::FilePerPicture => {
+                self.latex_document(&self.preamble, &self.body, engine)
+            }
+            OutputMode::PagePerPicture => {
+                let mut preamble = self.preamble.clone();
+                if let DocumentClass::Standalone(options) = self.preamble.class() {
+                    let multi = StandaloneOption::Multi("tikzpicture".to_string());
+                    let mut options = options.clone();
+                    match options
+                        .iter_mut()
+                        .find(|option| matches!(option, StandaloneOption::Multi(_)))
+                    {
+                        Some(option) => *option = multi,
+                        None => options.push(multi),
+                    }
+                    preamble.set_document_class(DocumentClass::Standalone(options));
+                }
+                self.latex_document(&preamble, &self.body, engine)
+            }
+        }
+    }
+
+    /// Return the [`String`]s with valid LaTeX code that generate the PDF documents when
+    /// compiled with the given [`LatexEngine`], one per output file according to the
+    /// [`OutputMode`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Document::latex_string`].
+    pub fn latex_strings(
+        &self,
+        engine: LatexEngine,
+    ) -> std::result::Result<Vec<String>, PreambleError> {
+        match self.output_mode {
+            OutputMode::SinglePage | OutputMode::PagePerPicture => {
+                Ok(vec![self.latex_string(engine)?])
+            }
+            OutputMode::FilePerPicture => self
                 .body
                 .iter()
-                .map(|picture| picture.to_string())
+                .map(|picture| {
+                    self.latex_document(&self.preamble, std::slice::from_ref(picture), engine)
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the expected number of pages of each output file, according to the
+    /// [`OutputMode`] and the document class: the actual count is read from the engine log
+    /// after compilation, e.g. pictures overflowing an `article` page span several pages.
+    fn pages_per_file(&self) -> usize {
+        match (self.output_mode, self.preamble.class()) {
+            (OutputMode::FilePerPicture, _) => 1,
+            // Each picture is placed in its own frame.
+            (_, DocumentClass::Beamer(_)) => self.body.len(),
+            (OutputMode::PagePerPicture, _) => self.body.len(),
+            (OutputMode::SinglePage, DocumentClass::Standalone(options))
+                if options.iter().any(|option| match option {
+                    StandaloneOption::Tikz => true,
+                    StandaloneOption::Multi(environment) => environment == "tikzpicture",
+                    _ => false,
+                }) =>
+            {
+                self.body.len()
+            }
+            (OutputMode::SinglePage, _) => 1,
+        }
+    }
+
+    /// Return a [`String`] with the LaTeX document made of `preamble` and `pictures`.
+    fn latex_document(
+        &self,
+        preamble: &Preamble,
+        pictures: &[TikzPicture],
+        engine: LatexEngine,
+    ) -> std::result::Result<String, PreambleError> {
+        let separator = match (self.output_mode, preamble.class()) {
+            (OutputMode::PagePerPicture, DocumentClass::Article(_)) => "\n\\clearpage\n",
+            _ => "\n",
+        };
+
+        Ok([
+            &preamble.display(engine)?.to_string(),
+            r"\begin{document}",
+            &pictures
+                .iter()
+                .map(|picture| match preamble.class() {
+                    DocumentClass::Beamer(_) => {
+                        format!("\\begin{{frame}}\n{picture}\n\\end{{frame}}")
+                    }
+                    _ => picture.to_string(),
+                })
                 .collect::<Vec<String>>()
-                .join("\n"),
+                .join(separator),
             r"\end{document}",
         ]
-        .join("\n")
+        .join("\n"))
+    }
+
+    /// Return a [`String`] with the LaTeX code of the document body only, i.e. the Ti*k*Z
+    /// pictures, to be included (e.g. with `\input`) into another LaTeX document.
+    ///
+    /// The preamble of the including document must satisfy the [`Document::requirements`].
+    pub fn fragment_string(&self) -> String {
+        self.body
+            .iter()
+            .map(TikzPicture::to_string)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Returns the [`Requirements`] the preamble of a LaTeX document including the
+    /// [`Document::fragment_string`] must satisfy: packages, PGFPlots and Ti*k*Z libraries,
+    /// PGFPlots compatibility layer and global styles.
+    ///
+    /// The document class and fonts are left to the including document.
+    ///
+    /// Returns an error if a package required by the preamble items conflicts with the
+    /// options of a package added by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::{tikzpicture::TikzPicture, Document};
+    ///
+    /// let mut document = Document::new();
+    /// document.add_picture(TikzPicture::new());
+    /// assert_eq!(
+    /// r#"\usepackage{pgfplots}
+    /// \pgfplotsset{compat=default}
+    /// "#,
+    /// document.requirements().unwrap().to_string());
+    /// ```
+    pub fn requirements(&self) -> Result<Requirements> {
+        Ok(self.preamble.requirements()?)
+    }
+
+    /// Writes the [`Document::fragment_string`] to the `.tex` file at `path`, overwriting it
+    /// if it exists, and returns the [`Requirements`] of the written fragment.
+    ///
+    /// Returns an error if the PGFPlots compatibility layer explicitly set is lower than the
+    /// one required by the document content, if the required packages conflict with the
+    /// ones added by the user, or if some coordinates can not be drawn on their axis (see
+    /// [`TikzPicture::check`]).
+    pub fn save_fragment<P>(&self, path: P) -> Result<Requirements>
+    where
+        P: AsRef<Path>,
+    {
+        self.preamble.check_pgfcompat()?;
+        let requirements = self.requirements()?;
+        self.body.iter().try_for_each(TikzPicture::check)?;
+
+        let path = path.as_ref();
+        fs::write(path, self.fragment_string())
+            .map_err(|error| LatexOutputSaveError::SaveFail(path.to_path_buf(), error))?;
+
+        Ok(requirements)
     }
 
-    /// Compile the picture environment into a standalone PDF document. This
+    /// Compile the picture environment into a PDF document. This
     /// will create a `pgfplot.pdf` file in the system temporary directory (e.g. `/tmp` on Linux
     /// systems). Additional files will be created in the same directory (e.g. `.log` and
     /// `.aux` files).
+    ///
+    /// With [`OutputMode::FilePerPicture`], one `pgfplot-{n}.pdf` file is created for each
+    /// picture instead; see [`LatexOutput::output_paths`] and [`LatexOutput::pages`].
+    ///
+    /// See [`Document::reproducible`] to produce byte-identical PDFs across compilations.
+    ///
+    /// Returns an error if the PGFPlots compatibility layer explicitly set is lower than the
+    /// one required by the document content, if the packages required by the fonts, metadata
+    /// or preamble items conflict with the ones added by the user, if some axes can not be
+    /// laid out (see [`TikzPicture::check_layout`]) or if some coordinates can not be drawn on
+    /// their axis (see [`TikzPicture::check`]).
     pub fn pdf(&self, engine: LatexEngine) -> Result<LatexOutput> {
+        self.preamble.check_pgfcompat()?;
+        let sources = self.latex_strings(engine)?;
+        self.body.iter().try_for_each(TikzPicture::check_layout)?;
+        self.body.iter().try_for_each(TikzPicture::check)?;
+
         // Copy the tex code to a temporary file instead of passing it directly
         // to the engine via e.g. stdin. This avoids the "Argument list too
         // long" error when there are e.g. too many points in a plot.
-        let latex_output = LatexOutput::new()?;
-        latex_output.compile(engine, self.standalone_string())?;
+        let mut latex_output = LatexOutput::new()?;
+        if let Some(epoch) = self.source_date_epoch {
+            latex_output.set_source_date_epoch(epoch);
+        }
+        if self.body.iter().any(TikzPicture::has_references) {
+            latex_output.set_passes(2);
+        }
+        latex_output.compile(engine, &sources, self.output_mode, self.pages_per_file())?;
 
         Ok(latex_output)
     }
@@ -184,7 +703,7 @@ impl From<Preamble> for Document {
     fn from(preamble: Preamble) -> Self {
         Self {
             preamble,
-            body: vec![],
+            ..Default::default()
         }
     }
 }
@@ -240,4 +759,256 @@ mod test {
     //                 document.standalone_string()
     //             );
     // }
+
+    use super::*;
+    use crate::error::PgfPlotsError;
+    use length::{BarLength, Length};
+    use preamble::{AspectRatio, BeamerOption, FontFamily, FontSize, PgfPlotsCompatError};
+    use tikzpicture::axis::{
+        colormap::Colormap,
+        plot::bidimensional::{Plot2D, PlotOption, Type2D},
+        AxisOption,
+    };
+
+    #[test]
+    fn fonts() {
+        let document =
+            Document::new().fonts(Fonts::new(FontFamily::Libertinus).size(FontSize::Pt11));
+        assert_eq!(
+            r#"\documentclass[11pt]{standalone}
+\usepackage[T1]{fontenc}
+\usepackage{pgfplots}
+\usepackage{libertinus}
+\pgfplotsset{compat=default}
+
+\begin{document}
+
+\end{document}"#,
+            document.latex_string(LatexEngine::PdfLatex).unwrap()
+        );
+        assert_eq!(
+            r#"\documentclass[11pt]{standalone}
+\usepackage{pgfplots}
+\usepackage{fontspec}
+\usepackage{unicode-math}
+\setmainfont{Libertinus Serif}
+\setsansfont{Libertinus Sans}
+\setmathfont{Libertinus Math}
+\pgfplotsset{compat=default}
+
+\begin{document}
+
+\end{document}"#,
+            document.latex_string(LatexEngine::LuaLatex).unwrap()
+        );
+    }
+
+    #[test]
+    fn pgfcompat() {
+        let plot = Plot2D::new().option(PlotOption::Type2D(Type2D::YBar {
+            bar_width: BarLength::Axis(0.5),
+            bar_shift: BarLength::Axis(0.0),
+        }));
+
+        let mut document = Document::new();
+        document.add_picture(Axis::from(plot.clone()));
+        assert!(document
+            .standalone_string()
+            .unwrap()
+            .contains("\\pgfplotsset{compat=1.7}"));
+
+        let mut document = Document::new().pgfcompat(PgfPlotsCompat::V1_5);
+        document.add_picture(Axis::from(plot));
+        assert!(matches!(
+            document.pdf(LatexEngine::PdfLatex),
+            Err(PgfPlotsError::Compat(PgfPlotsCompatError::TooLow { .. }))
+        ));
+
+        let plot = Plot2D::new().option(PlotOption::Type2D(Type2D::YBar {
+            bar_width: Length::Pt(10.0).into(),
+            bar_shift: Length::Pt(0.0).into(),
+        }));
+        assert_eq!(PgfPlotsCompat::Default, plot.required_pgfcompat());
+
+        let axis = Axis::new().option(AxisOption::XLabel("$x$".to_string()));
+        assert_eq!(PgfPlotsCompat::V1_3, axis.required_pgfcompat());
+        let mut document = Document::new();
+        document.add_picture(axis.clone());
+        assert!(document
+            .standalone_string()
+            .unwrap()
+            .contains("\\pgfplotsset{compat=1.3}"));
+
+        let mut document = Document::new().pgfcompat(PgfPlotsCompat::Pre1_3);
+        document.add_picture(axis);
+        assert!(matches!(
+            document.pdf(LatexEngine::PdfLatex),
+            Err(PgfPlotsError::Compat(PgfPlotsCompatError::TooLow {
+                required: PgfPlotsCompat::V1_3,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn fragment() {
+        let mut document = Document::new().tikzlib(TikzLib::Calc);
+        let highlight = document.add_style("highlight", ["thick"]);
+        document.add_picture(Axis::from(
+            Plot2D::new()
+                .option(PlotOption::Style(highlight))
+                .option(PlotOption::Type2D(Type2D::YBar {
+                    bar_width: BarLength::Axis(0.5),
+                    bar_shift: BarLength::Axis(0.0),
+                })),
+        ));
+
+        let fragment = document.fragment_string();
+        assert!(fragment.starts_with("\\begin{tikzpicture}"));
+        assert!(fragment.ends_with("\\end{tikzpicture}"));
+        assert!(!fragment.contains("\\documentclass"));
+
+        let requirements = document.requirements().unwrap();
+        assert_eq!(PgfPlotsCompat::V1_7, requirements.pgfcompat());
+        assert_eq!(&[TikzLib::Calc], requirements.tikzlibs());
+        assert_eq!(
+            r#"\usepackage{pgfplots}
+\pgfplotsset{compat=1.7}
+\usetikzlibrary{calc}
+\pgfplotsset{
+	highlight/.style={thick},
+}
+"#,
+            requirements.to_string()
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("figure.tex");
+        assert_eq!(requirements, document.save_fragment(&path).unwrap());
+        assert_eq!(fragment, fs::read_to_string(path).unwrap());
+    }
+
+    #[test]
+    fn output_mode() {
+        let document = Document::new()
+            .picture(TikzPicture::new())
+            .picture(TikzPicture::new());
+        assert_eq!(
+            1,
+            document.latex_strings(LatexEngine::PdfLatex).unwrap().len()
+        );
+
+        let document = document.output_mode(OutputMode::FilePerPicture);
+        let sources = document.latex_strings(LatexEngine::PdfLatex).unwrap();
+        assert_eq!(2, sources.len());
+        assert_eq!(sources, document.standalone_strings().unwrap());
+        for source in sources {
+            assert_eq!(1, source.matches("\\begin{tikzpicture}").count());
+        }
+
+        let document = Document::new()
+            .document_class(DocumentClass::Article(vec![]))
+            .output_mode(OutputMode::PagePerPicture)
+            .picture(TikzPicture::new())
+            .picture(TikzPicture::new());
+        assert!(document
+            .standalone_string()
+            .unwrap()
+            .contains("\\end{tikzpicture}\n\\clearpage\n\\begin{tikzpicture}"));
+        assert_eq!(2, document.pages_per_file());
+
+        let document = Document::new()
+            .document_class(DocumentClass::Standalone(vec![StandaloneOption::Multi(
+                "axis".to_string(),
+            )]))
+            .output_mode(OutputMode::PagePerPicture)
+            .picture(TikzPicture::new());
+        assert!(document
+            .standalone_string()
+            .unwrap()
+            .starts_with("\\documentclass[multi=tikzpicture]{standalone}"));
+
+        let document = Document::new()
+            .document_class(DocumentClass::Beamer(vec![]))
+            .picture(TikzPicture::new())
+            .picture(TikzPicture::new());
+        assert_eq!(2, document.pages_per_file());
+        let document = document.output_mode(OutputMode::FilePerPicture);
+        assert_eq!(1, document.pages_per_file());
+    }
+
+    #[test]
+    fn viridis() {
+        let axis = Axis::new().option(AxisOption::Colormap(Colormap::Viridis));
+        let document = Document::from(axis);
+        assert_eq!(
+            r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\pgfplotsset{compat=default}
+
+\begin{document}
+\begin{tikzpicture}
+\begin{axis}[
+	colormap/viridis,
+]
+\end{axis}
+\end{tikzpicture}
+\end{document}"#,
+            document.standalone_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn registered_colormaps() {
+        let mut document = Document::new();
+        let heat = document.add_colormap("heat", [(0.0, [0, 0, 255]), (1.0, [255, 0, 0])]);
+        document.add_colormap("cold", [(0.0, [255, 255, 255]), (1.0, [0, 0, 255])]);
+        document.add_picture(Axis::new());
+        document.add_picture(Axis::new().option(AxisOption::Colormap(Colormap::Registered(heat))));
+        assert_eq!(
+            r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\pgfplotsset{compat=default}
+\pgfplotsset{
+	colormap/heat/.style={colormap={heat}{rgb255(0cm)=(0,0,255); rgb255(1cm)=(255,0,0)}},
+	colormap/cold/.style={colormap={cold}{rgb255(0cm)=(255,255,255); rgb255(1cm)=(0,0,255)}},
+}
+
+\begin{document}
+\begin{tikzpicture}
+\begin{axis}
+\end{axis}
+\end{tikzpicture}
+\begin{tikzpicture}
+\begin{axis}[
+	colormap/heat,
+]
+\end{axis}
+\end{tikzpicture}
+\end{document}"#,
+            document.standalone_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn beamer_frames() {
+        let mut document =
+            Document::new().document_class(DocumentClass::Beamer(vec![BeamerOption::AspectRatio(
+                AspectRatio::Wide,
+            )]));
+        document.add_picture(TikzPicture::new());
+        assert_eq!(
+            r#"\documentclass[aspectratio=169]{beamer}
+\usepackage{pgfplots}
+\pgfplotsset{compat=default}
+
+\begin{document}
+\begin{frame}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{frame}
+\end{document}"#,
+            document.standalone_string().unwrap()
+        );
+    }
 }
@@ -0,0 +1,229 @@
+use crate::document::length::Length;
+use std::fmt;
+
+/// LaTeX document class used to compile the document.
+///
+/// ```text
+/// \documentclass[options]{class}
+/// ```
+///
+/// The document class determines whether the produced PDF is a cropped figure
+/// ([`DocumentClass::Standalone`]), a full page of figures ([`DocumentClass::Article`])
+/// or a presentation slide ([`DocumentClass::Beamer`]).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum DocumentClass {
+    /// Cropped output, with the page fitting the content (`standalone` class).
+    Standalone(Vec<StandaloneOption>),
+    /// Full page output (`article` class).
+    Article(Vec<ArticleOption>),
+    /// Presentation slides output (`beamer` class): each picture is placed in its own frame.
+    Beamer(Vec<BeamerOption>),
+}
+
+impl Default for DocumentClass {
+    fn default() -> Self {
+        Self::Standalone(vec![])
+    }
+}
+
+impl fmt::Display for DocumentClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\\documentclass")?;
+        let options = self.options();
+        if !options.is_empty() {
+            write!(f, "[{}]", options.join(", "))?;
+        }
+
+        write!(f, "{{{}}}", self.name())
+    }
+}
+
+impl DocumentClass {
+    /// Returns the LaTeX name of the [`DocumentClass`].
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Standalone(_) => "standalone",
+            Self::Article(_) => "article",
+            Self::Beamer(_) => "beamer",
+        }
+    }
+
+    /// Returns a copy of the [`DocumentClass`] with an additional custom option.
+    pub(crate) fn with_option(&self, option: String) -> Self {
+        let mut class = self.clone();
+        match &mut class {
+            Self::Standalone(options) => options.push(StandaloneOption::Custom(option)),
+            Self::Article(options) => options.push(ArticleOption::Custom(option)),
+            Self::Beamer(options) => options.push(BeamerOption::Custom(option)),
+        }
+
+        class
+    }
+
+    /// Returns the [`DocumentClass`] options, rendered as LaTeX.
+    pub(crate) fn options(&self) -> Vec<String> {
+        match self {
+            Self::Standalone(options) => options.iter().map(|opt| opt.to_string()).collect(),
+            Self::Article(options) => options.iter().map(|opt| opt.to_string()).collect(),
+            Self::Beamer(options) => options.iter().map(|opt| opt.to_string()).collect(),
+        }
+    }
+}
+
+/// Options passed to the `standalone` [`DocumentClass`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum StandaloneOption {
+    /// Custom class option not yet implemented.
+    /// This will be written verbatim in the class options.
+    Custom(String),
+    /// Border around the cropped content.
+    Border(Length),
+    /// Wrap the content in a `varwidth` environment, allowing paragraphs and
+    /// displayed equations.
+    Varwidth(bool),
+    /// Load Ti*k*Z and put each `tikzpicture` environment on its own cropped page.
+    Tikz,
+    /// Produce one page per given environment (e.g. `tikzpicture`).
+    Multi(String),
+}
+
+impl fmt::Display for StandaloneOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::Border(value) => write!(f, "border={value}"),
+            Self::Varwidth(value) => write!(f, "varwidth={value}"),
+            Self::Tikz => write!(f, "tikz"),
+            Self::Multi(env) => write!(f, "multi={env}"),
+        }
+    }
+}
+
+/// Options passed to the `article` [`DocumentClass`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ArticleOption {
+    /// Custom class option not yet implemented.
+    /// This will be written verbatim in the class options.
+    Custom(String),
+    /// Paper size of the document.
+    PaperSize(PaperSize),
+    /// Landscape page orientation.
+    Landscape,
+}
+
+impl fmt::Display for ArticleOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::PaperSize(value) => write!(f, "{value}"),
+            Self::Landscape => write!(f, "landscape"),
+        }
+    }
+}
+
+/// Options passed to the `beamer` [`DocumentClass`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum BeamerOption {
+    /// Custom class option not yet implemented.
+    /// This will be written verbatim in the class options.
+    Custom(String),
+    /// Paper size of the slides.
+    PaperSize(PaperSize),
+    /// Aspect ratio of the slides.
+    AspectRatio(AspectRatio),
+}
+
+impl fmt::Display for BeamerOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::PaperSize(value) => write!(f, "{value}"),
+            Self::AspectRatio(value) => write!(f, "aspectratio={value}"),
+        }
+    }
+}
+
+/// Paper size of the document page.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum PaperSize {
+    #[default]
+    A4,
+    A5,
+    B5,
+    Letter,
+    Legal,
+    Executive,
+}
+
+impl fmt::Display for PaperSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::A4 => "a4paper",
+            Self::A5 => "a5paper",
+            Self::B5 => "b5paper",
+            Self::Letter => "letterpaper",
+            Self::Legal => "legalpaper",
+            Self::Executive => "executivepaper",
+        })
+    }
+}
+
+/// Aspect ratio of `beamer` slides.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum AspectRatio {
+    /// 16:9 aspect ratio.
+    #[default]
+    Wide,
+    /// 16:10 aspect ratio.
+    Wide1610,
+    /// 4:3 aspect ratio.
+    Standard,
+}
+
+impl fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Wide => "169",
+            Self::Wide1610 => "1610",
+            Self::Standard => "43",
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn document_class() {
+        assert_eq!(
+            "\\documentclass{standalone}",
+            DocumentClass::default().to_string()
+        );
+        assert_eq!(
+            "\\documentclass[border=2pt, varwidth=true, multi=tikzpicture]{standalone}",
+            DocumentClass::Standalone(vec![
+                StandaloneOption::Border(Length::Pt(2.0)),
+                StandaloneOption::Varwidth(true),
+                StandaloneOption::Multi("tikzpicture".to_string()),
+            ])
+            .to_string()
+        );
+        assert_eq!(
+            "\\documentclass[a4paper, landscape]{article}",
+            DocumentClass::Article(vec![
+                ArticleOption::PaperSize(PaperSize::A4),
+                ArticleOption::Landscape,
+            ])
+            .to_string()
+        );
+        assert_eq!(
+            "\\documentclass[aspectratio=169]{beamer}",
+            DocumentClass::Beamer(vec![BeamerOption::AspectRatio(AspectRatio::Wide)]).to_string()
+        );
+    }
+}
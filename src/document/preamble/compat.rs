@@ -5,6 +5,14 @@ use std::{error, fmt};
 pub enum PgfPlotsCompatError {
     /// Compatibility version does not exists.
     BadCompatVersion(String),
+    /// Compatibility version explicitly set is lower than the one required by the document
+    /// content.
+    TooLow {
+        /// Compatibility version explicitly set.
+        version: PgfPlotsCompat,
+        /// Minimum compatibility version required.
+        required: PgfPlotsCompat,
+    },
 }
 
 impl fmt::Display for PgfPlotsCompatError {
@@ -16,6 +24,13 @@ impl fmt::Display for PgfPlotsCompatError {
                     available values are: {}",
                 VERSIONS.join(", ")
             ),
+            Self::TooLow { version, required } => write!(
+                f,
+                "pgfplots compatibility version `{}` is too low; \
+                    the document requires at least `{}`",
+                version.version(),
+                required.version()
+            ),
         }
     }
 }
@@ -29,9 +44,37 @@ const VERSIONS: [&str; 19] = [
 ];
 
 /// PGFPlots compatibility layer.
-#[derive(Debug, Clone)]
-pub struct PgfPlotsCompat {
-    version: String,
+///
+/// Compatibility versions are ordered, [`PgfPlotsCompat::Default`] being the lowest (i.e. the
+/// most backwards compatible) one. Axis and plot options whose meaning depends on the
+/// compatibility layer declare the minimum version they require, e.g. axis labels require
+/// [`PgfPlotsCompat::V1_3`] to be placed next to the tick labels, and bar widths and shifts in
+/// axis units (see [`crate::document::tikzpicture::axis::plot::bidimensional::Type2D::YBar`])
+/// require [`PgfPlotsCompat::V1_7`]: the [`crate::document::Document`] compatibility version is
+/// raised accordingly, unless explicitly set. Other options are not affected by the
+/// compatibility layer and require [`PgfPlotsCompat::Default`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PgfPlotsCompat {
+    #[default]
+    Default,
+    Pre1_3,
+    V1_3,
+    V1_4,
+    V1_5,
+    V1_5_1,
+    V1_6,
+    V1_7,
+    V1_8,
+    V1_9,
+    V1_10,
+    V1_11,
+    V1_12,
+    V1_13,
+    V1_14,
+    V1_15,
+    V1_16,
+    V1_17,
+    V1_18,
 }
 
 impl TryFrom<&str> for PgfPlotsCompat {
@@ -44,29 +87,61 @@ impl TryFrom<&str> for PgfPlotsCompat {
 
 impl fmt::Display for PgfPlotsCompat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\\pgfplotsset{{compat={}}}", self.version)
-    }
-}
-
-impl Default for PgfPlotsCompat {
-    fn default() -> Self {
-        Self {
-            version: "default".to_string(),
-        }
+        write!(f, "\\pgfplotsset{{compat={}}}", self.version())
     }
 }
 
 impl PgfPlotsCompat {
     /// Construct a new [`PgfPlotsCompat`], checking wheter the version is valid.
     fn new(version: &str) -> Result<Self, PgfPlotsCompatError> {
-        if !VERSIONS.contains(&version) {
-            return Err(PgfPlotsCompatError::BadCompatVersion(version.to_string()));
-        }
-
-        Ok(Self {
-            version: version.to_string(),
+        Ok(match version {
+            "default" => Self::Default,
+            "pre1.3" => Self::Pre1_3,
+            "1.3" => Self::V1_3,
+            "1.4" => Self::V1_4,
+            "1.5" => Self::V1_5,
+            "1.5.1" => Self::V1_5_1,
+            "1.6" => Self::V1_6,
+            "1.7" => Self::V1_7,
+            "1.8" => Self::V1_8,
+            "1.9" => Self::V1_9,
+            "1.10" => Self::V1_10,
+            "1.11" => Self::V1_11,
+            "1.12" => Self::V1_12,
+            "1.13" => Self::V1_13,
+            "1.14" => Self::V1_14,
+            "1.15" => Self::V1_15,
+            "1.16" => Self::V1_16,
+            "1.17" => Self::V1_17,
+            "1.18" => Self::V1_18,
+            _ => return Err(PgfPlotsCompatError::BadCompatVersion(version.to_string())),
         })
     }
+
+    /// Returns the compatibility version, as passed to `\pgfplotsset{compat=...}`.
+    pub fn version(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Pre1_3 => "pre1.3",
+            Self::V1_3 => "1.3",
+            Self::V1_4 => "1.4",
+            Self::V1_5 => "1.5",
+            Self::V1_5_1 => "1.5.1",
+            Self::V1_6 => "1.6",
+            Self::V1_7 => "1.7",
+            Self::V1_8 => "1.8",
+            Self::V1_9 => "1.9",
+            Self::V1_10 => "1.10",
+            Self::V1_11 => "1.11",
+            Self::V1_12 => "1.12",
+            Self::V1_13 => "1.13",
+            Self::V1_14 => "1.14",
+            Self::V1_15 => "1.15",
+            Self::V1_16 => "1.16",
+            Self::V1_17 => "1.17",
+            Self::V1_18 => "1.18",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +155,29 @@ mod test {
         assert!(PgfPlotsCompat::try_from("default").is_ok());
     }
 
+    #[test]
+    fn versions() {
+        for version in VERSIONS {
+            assert_eq!(
+                version,
+                PgfPlotsCompat::try_from(version).unwrap().version()
+            );
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(PgfPlotsCompat::Default < PgfPlotsCompat::Pre1_3);
+        assert!(PgfPlotsCompat::V1_5 < PgfPlotsCompat::V1_5_1);
+        assert!(PgfPlotsCompat::V1_9 < PgfPlotsCompat::V1_10);
+        assert_eq!(
+            PgfPlotsCompat::V1_18,
+            PgfPlotsCompat::try_from("1.18")
+                .unwrap()
+                .max(PgfPlotsCompat::V1_7)
+        );
+    }
+
     #[test]
     fn display() {
         assert_eq!(
@@ -0,0 +1,169 @@
+use super::Package;
+use crate::engine::LatexEngine;
+use std::fmt;
+
+/// Font family used to typeset the document (labels, titles, tick labels, etc.).
+///
+/// The preamble required to load a font family depends on the [`LatexEngine`]: `pdflatex`
+/// uses Type 1 fonts through `fontenc` and the family packages, while the Unicode engines
+/// (`lualatex` and `tectonic`) load OpenType fonts through `fontspec`.
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
+pub enum FontFamily {
+    /// Custom font family: `package` is loaded by `pdflatex`, while `font` is the OpenType
+    /// font name set with `\setmainfont` by the Unicode engines.
+    Custom { package: String, font: String },
+    /// Latin Modern, the default LaTeX font family.
+    #[default]
+    LatinModern,
+    /// Times, through `newtx` (`pdflatex`) or TeX Gyre Termes (Unicode engines).
+    Times,
+    /// Libertinus, with matching math fonts.
+    Libertinus,
+}
+
+/// Base font size of the document, passed as document class option.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum FontSize {
+    #[default]
+    Pt10,
+    Pt11,
+    Pt12,
+}
+
+impl fmt::Display for FontSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Pt10 => "10pt",
+            Self::Pt11 => "11pt",
+            Self::Pt12 => "12pt",
+        })
+    }
+}
+
+/// Typography configuration of the document.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::{
+///     preamble::{FontFamily, FontSize, Fonts},
+///     Document,
+/// };
+///
+/// let document = Document::new().fonts(Fonts::new(FontFamily::Times).size(FontSize::Pt11));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Fonts {
+    /// Font family.
+    family: FontFamily,
+    /// Base font size: if [`None`] the document class default is used.
+    size: Option<FontSize>,
+}
+
+impl From<FontFamily> for Fonts {
+    fn from(family: FontFamily) -> Self {
+        Self::new(family)
+    }
+}
+
+impl Fonts {
+    /// Constructs a new [`Fonts`] configuration using the given [`FontFamily`].
+    pub fn new(family: FontFamily) -> Self {
+        Self { family, size: None }
+    }
+
+    /// Set the base font size.
+    pub fn size(mut self, size: FontSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the base font size.
+    pub fn set_size(&mut self, size: FontSize) {
+        self.size = Some(size);
+    }
+
+    /// Returns the base font size, if set.
+    pub(crate) fn get_size(&self) -> Option<FontSize> {
+        self.size
+    }
+
+    /// Returns the packages required by the font family with the given [`LatexEngine`].
+    pub(crate) fn pkgs(&self, engine: LatexEngine) -> Vec<Package> {
+        if engine.is_unicode() {
+            return match self.family {
+                FontFamily::Times | FontFamily::Libertinus => {
+                    vec!["fontspec".into(), "unicode-math".into()]
+                }
+                _ => vec!["fontspec".into()],
+            };
+        }
+
+        let fontenc = Package::new("fontenc", &["T1"]);
+        match &self.family {
+            FontFamily::Custom { package, .. } => vec![fontenc, package.as_str().into()],
+            FontFamily::LatinModern => vec![fontenc, "lmodern".into()],
+            FontFamily::Times => vec![fontenc, "newtxtext".into(), "newtxmath".into()],
+            FontFamily::Libertinus => vec![fontenc, "libertinus".into()],
+        }
+    }
+
+    /// Returns the font selection commands, to be written after the packages are loaded.
+    pub(crate) fn commands(&self, engine: LatexEngine) -> Vec<String> {
+        if !engine.is_unicode() {
+            return vec![];
+        }
+
+        match &self.family {
+            FontFamily::Custom { font, .. } => vec![format!("\\setmainfont{{{font}}}")],
+            FontFamily::LatinModern => vec!["\\setmainfont{Latin Modern Roman}".to_string()],
+            FontFamily::Times => vec![
+                "\\setmainfont{TeX Gyre Termes}".to_string(),
+                "\\setmathfont{TeX Gyre Termes Math}".to_string(),
+            ],
+            FontFamily::Libertinus => vec![
+                "\\setmainfont{Libertinus Serif}".to_string(),
+                "\\setsansfont{Libertinus Sans}".to_string(),
+                "\\setmathfont{Libertinus Math}".to_string(),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fonts() {
+        let fonts = Fonts::new(FontFamily::Times);
+        assert_eq!(
+            "\\usepackage[T1]{fontenc} \\usepackage{newtxtext} \\usepackage{newtxmath}",
+            fonts
+                .pkgs(LatexEngine::PdfLatex)
+                .iter()
+                .map(|pkg| pkg.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+        assert!(fonts.commands(LatexEngine::PdfLatex).is_empty());
+
+        assert_eq!(
+            "\\usepackage{fontspec} \\usepackage{unicode-math}",
+            fonts
+                .pkgs(LatexEngine::LuaLatex)
+                .iter()
+                .map(|pkg| pkg.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+        assert_eq!(
+            vec![
+                "\\setmainfont{TeX Gyre Termes}",
+                "\\setmathfont{TeX Gyre Termes Math}"
+            ],
+            fonts.commands(LatexEngine::LuaLatex)
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use super::Package;
+use crate::error::PreambleError;
+use std::fmt;
+
+/// Position of a [`PreambleItem`] in the document preamble, relative to the packages.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// Right after the document class, before any package is loaded (e.g. for
+    /// `\PassOptionsToPackage`).
+    BeforePackages,
+    /// After all the packages are loaded, before the PGFPlots settings (compatibility layer,
+    /// libraries and styles), so that styles can make use of the defined macros and colors.
+    #[default]
+    AfterPackages,
+}
+
+/// Color model of a [`PreambleItem::Color`] definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColorModel {
+    /// Red, green and blue components in `[0, 1]`, e.g. `0.1, 0.5, 0.9` (`rgb`).
+    Rgb,
+    /// Red, green and blue components in `[0, 255]`, e.g. `25, 128, 230` (`RGB`).
+    Rgb255,
+    /// Hexadecimal RGB triplet, e.g. `1A80E6` (`HTML`).
+    Html,
+    /// Cyan, magenta, yellow and black components in `[0, 1]` (`cmyk`).
+    Cmyk,
+    /// Gray level in `[0, 1]` (`gray`).
+    Gray,
+}
+
+impl fmt::Display for ColorModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Rgb => "rgb",
+            Self::Rgb255 => "RGB",
+            Self::Html => "HTML",
+            Self::Cmyk => "cmyk",
+            Self::Gray => "gray",
+        })
+    }
+}
+
+/// Typed content of the document preamble, other than packages and libraries.
+///
+/// Macro and operator names can be given with or without the leading backslash.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::preamble::PreambleItem;
+///
+/// let item = PreambleItem::Macro {
+///     name: "norm".to_string(),
+///     arity: 1,
+///     definition: r"\left\lVert#1\right\rVert".to_string(),
+/// };
+/// assert_eq!(
+///     r"\newcommand{\norm}[1]{\left\lVert#1\right\rVert}",
+///     item.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PreambleItem {
+    /// Macro taking `arity` (at most 9) arguments
+    /// (`\newcommand{\name}[arity]{definition}`).
+    Macro {
+        name: String,
+        arity: u8,
+        definition: String,
+    },
+    /// Math operator, e.g. `\argmax` (`\DeclareMathOperator{\name}{operator}`): requires
+    /// the `amsmath` package, which is loaded automatically.
+    MathOperator { name: String, operator: String },
+    /// Color definition (`\definecolor{name}{model}{spec}`): requires the `xcolor` package,
+    /// which is loaded automatically.
+    Color {
+        name: String,
+        model: ColorModel,
+        spec: String,
+    },
+    /// Raw LaTeX line, written as is.
+    Raw(String),
+}
+
+impl fmt::Display for PreambleItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Macro {
+                name,
+                arity,
+                definition,
+            } => {
+                write!(f, "\\newcommand{{\\{}}}", control_sequence(name))?;
+                if *arity > 0 {
+                    write!(f, "[{arity}]")?;
+                }
+                write!(f, "{{{definition}}}")
+            }
+            Self::MathOperator { name, operator } => write!(
+                f,
+                "\\DeclareMathOperator{{\\{}}}{{{operator}}}",
+                control_sequence(name)
+            ),
+            Self::Color { name, model, spec } => {
+                write!(f, "\\definecolor{{{name}}}{{{model}}}{{{spec}}}")
+            }
+            Self::Raw(line) => write!(f, "{line}"),
+        }
+    }
+}
+
+impl From<&str> for PreambleItem {
+    fn from(line: &str) -> Self {
+        Self::Raw(line.to_string())
+    }
+}
+
+impl PreambleItem {
+    /// Returns the package required by the item, if any.
+    pub(crate) fn required_pkg(&self) -> Option<Package> {
+        match self {
+            Self::MathOperator { .. } => Some("amsmath".into()),
+            Self::Color { .. } => Some("xcolor".into()),
+            _ => None,
+        }
+    }
+
+    /// Checks that the item is well-formed.
+    pub(crate) fn check(&self) -> Result<(), PreambleError> {
+        match self {
+            Self::Macro { name, arity, .. } if *arity > 9 => Err(PreambleError::MacroArity {
+                name: control_sequence(name).to_string(),
+                arity: *arity,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns whether `self` and `other` define the same macro, operator or color, in which
+    /// case the latter replaces the former.
+    pub(crate) fn same_entry(&self, other: &PreambleItem) -> bool {
+        match (self, other) {
+            (
+                Self::Macro { name, .. } | Self::MathOperator { name, .. },
+                Self::Macro { name: other, .. } | Self::MathOperator { name: other, .. },
+            ) => control_sequence(name) == control_sequence(other),
+            (Self::Color { name, .. }, Self::Color { name: other, .. }) => name == other,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the control sequence `name` without the leading backslash.
+fn control_sequence(name: &str) -> &str {
+    name.trim_start_matches('\\')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn preamble_item() {
+        let item = PreambleItem::Macro {
+            name: "\\R".to_string(),
+            arity: 0,
+            definition: "\\mathbb{R}".to_string(),
+        };
+        assert_eq!("\\newcommand{\\R}{\\mathbb{R}}", item.to_string());
+        assert!(item.check().is_ok());
+
+        let operator = PreambleItem::MathOperator {
+            name: "R".to_string(),
+            operator: "Re".to_string(),
+        };
+        assert_eq!("\\DeclareMathOperator{\\R}{Re}", operator.to_string());
+        assert!(item.same_entry(&operator));
+        assert_eq!(Some(Package::from("amsmath")), operator.required_pkg());
+
+        let color = PreambleItem::Color {
+            name: "accent".to_string(),
+            model: ColorModel::Html,
+            spec: "1A80E6".to_string(),
+        };
+        assert_eq!("\\definecolor{accent}{HTML}{1A80E6}", color.to_string());
+        assert_eq!(Some(Package::from("xcolor")), color.required_pkg());
+
+        assert!(matches!(
+            PreambleItem::Macro {
+                name: "f".to_string(),
+                arity: 10,
+                definition: String::new(),
+            }
+            .check(),
+            Err(PreambleError::MacroArity { arity: 10, .. })
+        ));
+    }
+}
@@ -0,0 +1,120 @@
+use super::Package;
+use std::fmt;
+
+/// PDF document metadata, written to the PDF information dictionary through `hyperref` and
+/// to the XMP metadata packet through `hyperxmp`.
+///
+/// ```text
+/// \hypersetup{pdftitle={title}, pdfauthor={author}, ...}
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::preamble::Metadata;
+///
+/// let metadata = Metadata::new()
+///     .title("Results")
+///     .author("Jane Doe")
+///     .keywords(["pgfplots", "rust"]);
+/// assert_eq!(
+///     r"\hypersetup{pdftitle={Results}, pdfauthor={Jane Doe}, pdfkeywords={pgfplots, rust}}",
+///     metadata.to_string()
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    /// Document title.
+    title: Option<String>,
+    /// Document authors.
+    authors: Vec<String>,
+    /// Document subject.
+    subject: Option<String>,
+    /// Document keywords.
+    keywords: Vec<String>,
+}
+
+impl fmt::Display for Metadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut options = Vec::new();
+        if let Some(title) = &self.title {
+            options.push(format!("pdftitle={{{title}}}"));
+        }
+        if !self.authors.is_empty() {
+            options.push(format!("pdfauthor={{{}}}", self.authors.join(", ")));
+        }
+        if let Some(subject) = &self.subject {
+            options.push(format!("pdfsubject={{{subject}}}"));
+        }
+        if !self.keywords.is_empty() {
+            options.push(format!("pdfkeywords={{{}}}", self.keywords.join(", ")));
+        }
+
+        write!(f, "\\hypersetup{{{}}}", options.join(", "))
+    }
+}
+
+impl Metadata {
+    /// Constructs a new empty [`Metadata`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the document title.
+    pub fn title(mut self, title: &str) -> Self {
+        self.set_title(title);
+        self
+    }
+
+    /// Add a document author.
+    pub fn author(mut self, author: &str) -> Self {
+        self.add_author(author);
+        self
+    }
+
+    /// Set the document subject.
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.set_subject(subject);
+        self
+    }
+
+    /// Add document keywords.
+    pub fn keywords<I>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.add_keywords(keywords);
+        self
+    }
+
+    /// Set the document title.
+    pub fn set_title(&mut self, title: &str) {
+        self.title = Some(title.to_string());
+    }
+
+    /// Add a document author.
+    pub fn add_author(&mut self, author: &str) {
+        self.authors.push(author.to_string());
+    }
+
+    /// Set the document subject.
+    pub fn set_subject(&mut self, subject: &str) {
+        self.subject = Some(subject.to_string());
+    }
+
+    /// Add document keywords.
+    pub fn add_keywords<I>(&mut self, keywords: I)
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.keywords
+            .extend(keywords.into_iter().map(|keyword| keyword.to_string()));
+    }
+
+    /// Returns the packages required to write the metadata.
+    pub(crate) fn pkgs(&self) -> Vec<Package> {
+        vec!["hyperref".into(), "hyperxmp".into()]
+    }
+}
@@ -1,29 +1,63 @@
+mod class;
 mod compat;
+mod fonts;
+mod item;
+mod metadata;
 mod package;
 mod pgfplotslib;
+mod requirements;
+mod style;
+mod tikzlib;
 
+use crate::{engine::LatexEngine, error::PreambleError};
+pub use class::{
+    ArticleOption, AspectRatio, BeamerOption, DocumentClass, PaperSize, StandaloneOption,
+};
 pub use compat::{PgfPlotsCompat, PgfPlotsCompatError};
+pub use fonts::{FontFamily, FontSize, Fonts};
+pub use item::{ColorModel, Placement, PreambleItem};
+pub use metadata::Metadata;
 pub use package::Package;
 pub use pgfplotslib::PgfPlotsLib;
+pub use requirements::Requirements;
 use std::fmt;
+pub use style::{ColorStop, ColormapRef, CycleListRef, EveryStyle, PgfPlotsStyle, StyleRef};
+pub use tikzlib::TikzLib;
 
 /// LaTeX document preamble.
 #[derive(Debug, Default, Clone)]
 pub struct Preamble {
-    /// LaTeX packages to include in the document compilation.
+    /// LaTeX document class.
+    class: DocumentClass,
+    /// Typography configuration: if [`None`] the LaTeX engine defaults are used.
+    fonts: Option<Fonts>,
+    /// PDF document metadata.
+    metadata: Option<Metadata>,
+    /// Whether the engine output must be reproducible.
+    reproducible: bool,
+    /// LaTeX packages to include in the document compilation, each appearing once with its
+    /// merged options.
     pkgs: Vec<Package>,
-    /// PGFPlots libraries which need to be activeated separately.
+    /// PGFPlots libraries which need to be activeated separately, each appearing once.
     pgflibs: Vec<PgfPlotsLib>,
-    /// PGFPlots compatibility layer.
-    pgfcompat: PgfPlotsCompat,
+    /// Ti*k*Z libraries, each appearing once.
+    tikzlibs: Vec<TikzLib>,
+    /// Global PGFPlots style registry (`\pgfplotsset{...}`).
+    styles: Vec<PgfPlotsStyle>,
+    /// Macros, colors and raw lines, with their placement.
+    items: Vec<(Placement, PreambleItem)>,
+    /// PGFPlots compatibility layer explicitly set: if [`None`], the minimum version
+    /// required by the document content is used.
+    pgfcompat: Option<PgfPlotsCompat>,
+    /// Minimum PGFPlots compatibility layer required by the document content.
+    required_pgfcompat: PgfPlotsCompat,
 }
 
 impl From<PgfPlotsCompat> for Preamble {
     fn from(pgfcompat: PgfPlotsCompat) -> Self {
         Self {
-            pkgs: vec![],
-            pgflibs: vec![],
-            pgfcompat,
+            pgfcompat: Some(pgfcompat),
+            ..Default::default()
         }
     }
 }
@@ -37,100 +71,436 @@ impl Preamble {
     /// Construct a new [`Preamble`] with specific PGFPlots compatibility layer version.
     pub fn with_pgfcompat_version(version: &str) -> Result<Self, PgfPlotsCompatError> {
         Ok(Self {
-            pkgs: vec![],
-            pgflibs: vec![],
-            pgfcompat: PgfPlotsCompat::try_from(version)?,
+            pgfcompat: Some(PgfPlotsCompat::try_from(version)?),
+            ..Default::default()
         })
     }
 
+    /// Set the LaTeX document class.
+    pub fn document_class(mut self, class: DocumentClass) -> Self {
+        self.class = class;
+        self
+    }
+
+    /// Set the typography configuration.
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = Some(fonts);
+        self
+    }
+
+    /// Set the PDF document metadata.
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
     /// Set PGFPlots compatibility layer.
     pub fn pgfcompat(mut self, pgfcompat: PgfPlotsCompat) -> Self {
-        self.pgfcompat = pgfcompat;
+        self.pgfcompat = Some(pgfcompat);
         self
     }
 
     /// Set PGFPlots compatibility layer version.
     pub fn pgfcompat_version(mut self, version: &str) -> Result<Self, PgfPlotsCompatError> {
-        self.pgfcompat = PgfPlotsCompat::try_from(version)?;
+        self.pgfcompat = Some(PgfPlotsCompat::try_from(version)?);
         Ok(self)
     }
 
-    /// Add a PGFPlots library to the document preamble.
+    /// Add a PGFPlots library to the document preamble, unless already present.
     pub fn pgflib(mut self, lib: PgfPlotsLib) -> Self {
-        self.pgflibs.push(lib);
+        self.add_pgflib(lib);
         self
     }
 
-    /// Add a PGFPlots libraries to the document preamble.
+    /// Add a PGFPlots libraries to the document preamble, skipping the ones already present.
     pub fn pgflibs(mut self, libs: &[PgfPlotsLib]) -> Self {
-        self.pgflibs.extend_from_slice(libs);
+        self.add_pgflibs(libs);
         self
     }
 
-    /// Add a LaTeX package to the document preamble.
-    pub fn pkg(mut self, pkg: Package) -> Self {
-        self.pkgs.push(pkg);
+    /// Add a Ti*k*Z library to the document preamble, unless already present.
+    pub fn tikzlib(mut self, lib: TikzLib) -> Self {
+        self.add_tikzlib(lib);
         self
     }
 
-    /// Add LaTeX packages to the document preamble.
-    pub fn pkgs(mut self, pkgs: &[Package]) -> Self {
-        self.pkgs.extend_from_slice(pkgs);
+    /// Add Ti*k*Z libraries to the document preamble, skipping the ones already present.
+    pub fn tikzlibs(mut self, libs: &[TikzLib]) -> Self {
+        self.add_tikzlibs(libs);
         self
     }
 
+    /// Add a LaTeX package to the document preamble.
+    ///
+    /// If the package is already present, its options are merged with the existing ones.
+    /// Returns an error if the package has already been loaded with conflicting options.
+    pub fn pkg(mut self, pkg: Package) -> Result<Self, PreambleError> {
+        self.add_pkg(pkg)?;
+        Ok(self)
+    }
+
+    /// Add LaTeX packages to the document preamble.
+    ///
+    /// If a package is already present, its options are merged with the existing ones.
+    /// Returns an error if a package has already been loaded with conflicting options.
+    pub fn pkgs(mut self, pkgs: &[Package]) -> Result<Self, PreambleError> {
+        self.add_pkgs(pkgs)?;
+        Ok(self)
+    }
+
+    /// Add a [`PreambleItem`] to the document preamble, after the packages.
+    ///
+    /// Returns an error if the item is malformed (e.g. a macro with more than 9 arguments).
+    pub fn item(mut self, item: PreambleItem) -> Result<Self, PreambleError> {
+        self.add_item(item)?;
+        Ok(self)
+    }
+
+    /// Add a [`PreambleItem`] to the document preamble, at the given [`Placement`].
+    ///
+    /// Returns an error if the item is malformed (e.g. a macro with more than 9 arguments).
+    pub fn item_at(
+        mut self,
+        item: PreambleItem,
+        placement: Placement,
+    ) -> Result<Self, PreambleError> {
+        self.add_item_at(item, placement)?;
+        Ok(self)
+    }
+
+    /// Set the LaTeX document class.
+    pub fn set_document_class(&mut self, class: DocumentClass) {
+        self.class = class;
+    }
+
+    /// Returns a reference to the LaTeX document class.
+    pub(crate) fn class(&self) -> &DocumentClass {
+        &self.class
+    }
+
+    /// Set the typography configuration.
+    pub fn set_fonts(&mut self, fonts: Fonts) {
+        self.fonts = Some(fonts);
+    }
+
+    /// Set the PDF document metadata.
+    pub fn set_metadata(&mut self, metadata: Metadata) {
+        self.metadata = Some(metadata);
+    }
+
+    /// Set whether the engine output must be reproducible: if so, the preamble suppresses the
+    /// engine-specific information changing at each compilation (e.g. the PDF trailer ID).
+    pub(crate) fn set_reproducible(&mut self, reproducible: bool) {
+        self.reproducible = reproducible;
+    }
+
     /// Set PGFPlots compatibility layer.
     pub fn set_pgfcompat(&mut self, pgfcompat: PgfPlotsCompat) {
-        self.pgfcompat = pgfcompat;
+        self.pgfcompat = Some(pgfcompat);
     }
 
     /// Set PGFPlots compatibility layer version.
     pub fn set_pgfcompat_version(&mut self, version: &str) -> Result<(), PgfPlotsCompatError> {
-        self.pgfcompat = PgfPlotsCompat::try_from(version)?;
+        self.pgfcompat = Some(PgfPlotsCompat::try_from(version)?);
 
         Ok(())
     }
 
-    /// Add a PGFPlots library to the document preamble.
+    /// Raise the minimum PGFPlots compatibility layer required by the document content.
+    pub fn require_pgfcompat(&mut self, pgfcompat: PgfPlotsCompat) {
+        self.required_pgfcompat = self.required_pgfcompat.max(pgfcompat);
+    }
+
+    /// Returns the PGFPlots compatibility layer of the document: the one explicitly set if
+    /// any, otherwise the minimum one required by the document content.
+    pub fn get_pgfcompat(&self) -> PgfPlotsCompat {
+        self.pgfcompat.unwrap_or(self.required_pgfcompat)
+    }
+
+    /// Checks that the PGFPlots compatibility layer explicitly set (if any) is not lower than
+    /// the minimum one required by the document content.
+    pub fn check_pgfcompat(&self) -> Result<(), PgfPlotsCompatError> {
+        match self.pgfcompat {
+            Some(version) if version < self.required_pgfcompat => {
+                Err(PgfPlotsCompatError::TooLow {
+                    version,
+                    required: self.required_pgfcompat,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Add a PGFPlots library to the document preamble, unless already present.
     pub fn add_pgflib(&mut self, lib: PgfPlotsLib) {
-        self.pgflibs.push(lib);
+        if !self.pgflibs.contains(&lib) {
+            self.pgflibs.push(lib);
+        }
     }
 
-    /// Add a PGFPlots libraries to the document preamble.
+    /// Add a PGFPlots libraries to the document preamble, skipping the ones already present.
     pub fn add_pgflibs(&mut self, libs: &[PgfPlotsLib]) {
-        self.pgflibs.extend_from_slice(libs);
+        for lib in libs {
+            self.add_pgflib(lib.clone());
+        }
+    }
+
+    /// Add a Ti*k*Z library to the document preamble, unless already present.
+    pub fn add_tikzlib(&mut self, lib: TikzLib) {
+        if !self.tikzlibs.contains(&lib) {
+            self.tikzlibs.push(lib);
+        }
+    }
+
+    /// Add Ti*k*Z libraries to the document preamble, skipping the ones already present.
+    pub fn add_tikzlibs(&mut self, libs: &[TikzLib]) {
+        for lib in libs {
+            self.add_tikzlib(lib.clone());
+        }
     }
 
     /// Add a LaTeX package to the document preamble.
-    pub fn add_pkg(&mut self, pkg: Package) {
-        self.pkgs.push(pkg);
+    ///
+    /// If the package is already present, its options are merged with the existing ones.
+    /// Returns an error if the package has already been loaded with conflicting options.
+    pub fn add_pkg(&mut self, pkg: Package) -> Result<(), PreambleError> {
+        match self.pkgs.iter_mut().find(|p| p.name() == pkg.name()) {
+            Some(loaded) => loaded.merge(&pkg),
+            None => {
+                self.pkgs.push(pkg);
+                Ok(())
+            }
+        }
     }
 
     /// Add LaTeX packages to the document preamble.
-    pub fn add_pkgs(&mut self, pkgs: &[Package]) {
-        self.pkgs.extend_from_slice(pkgs);
+    ///
+    /// If a package is already present, its options are merged with the existing ones.
+    /// Returns an error if a package has already been loaded with conflicting options.
+    pub fn add_pkgs(&mut self, pkgs: &[Package]) -> Result<(), PreambleError> {
+        for pkg in pkgs {
+            self.add_pkg(pkg.clone())?;
+        }
+
+        Ok(())
+    }
+
+    /// Add a [`PreambleItem`] to the document preamble, after the packages.
+    ///
+    /// Returns an error if the item is malformed (e.g. a macro with more than 9 arguments).
+    pub fn add_item(&mut self, item: PreambleItem) -> Result<(), PreambleError> {
+        self.add_item_at(item, Placement::default())
+    }
+
+    /// Add a [`PreambleItem`] to the document preamble, at the given [`Placement`].
+    ///
+    /// A macro, operator or color redefining one previously added replaces it. The packages
+    /// required by the item (e.g. `xcolor` for color definitions) are loaded automatically.
+    /// Returns an error if the item is malformed (e.g. a macro with more than 9 arguments).
+    pub fn add_item_at(
+        &mut self,
+        item: PreambleItem,
+        placement: Placement,
+    ) -> Result<(), PreambleError> {
+        item.check()?;
+        match self.items.iter_mut().find(|(_, i)| i.same_entry(&item)) {
+            Some(added) => *added = (placement, item),
+            None => self.items.push((placement, item)),
+        }
+
+        Ok(())
+    }
+
+    /// Register a named PGFPlots style (`name/.style={options}`), replacing any style
+    /// previously registered with the same name.
+    ///
+    /// Returns a [`StyleRef`] handle which can be used to apply the style to axes and plots.
+    pub fn add_style<I>(&mut self, name: &str, options: I) -> StyleRef
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.add_pgfplots_style(PgfPlotsStyle::Style {
+            name: name.to_string(),
+            options: options.into_iter().map(|opt| opt.to_string()).collect(),
+        });
+
+        StyleRef::new(name)
+    }
+
+    /// Append options to a predefined PGFPlots style (e.g. `every axis/.append style={...}`),
+    /// replacing any options previously appended to the same style.
+    pub fn add_every_style<I>(&mut self, target: EveryStyle, options: I)
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.add_pgfplots_style(PgfPlotsStyle::Every {
+            target,
+            options: options.into_iter().map(|opt| opt.to_string()).collect(),
+        });
+    }
+
+    /// Register a named cycle list, replacing any cycle list previously registered with the
+    /// same name. Each entry is the style of one plot in the cycle.
+    ///
+    /// Returns a [`CycleListRef`] handle which can be used to apply the cycle list to axes.
+    pub fn add_cycle_list<I>(&mut self, name: &str, entries: I) -> CycleListRef
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.add_pgfplots_style(PgfPlotsStyle::CycleList {
+            name: name.to_string(),
+            entries: entries.into_iter().map(|entry| entry.to_string()).collect(),
+        });
+
+        CycleListRef::new(name)
+    }
+
+    /// Register a named colormap interpolating the given color stops, replacing any colormap
+    /// previously registered with the same name.
+    ///
+    /// The colormap is only defined: it does not replace the default colormap, and applies
+    /// only to the axes selecting it.
+    ///
+    /// Returns a [`ColormapRef`] handle which can be used to apply the colormap to axes.
+    pub fn add_colormap<I>(&mut self, name: &str, stops: I) -> ColormapRef
+    where
+        I: IntoIterator,
+        I::Item: Into<ColorStop>,
+    {
+        self.add_pgfplots_style(PgfPlotsStyle::Colormap {
+            name: name.to_string(),
+            stops: stops.into_iter().map(Into::into).collect(),
+        });
+
+        ColormapRef::new(name)
+    }
+
+    /// Add an entry to the style registry, replacing the entry it redefines (if any).
+    fn add_pgfplots_style(&mut self, style: PgfPlotsStyle) {
+        match self.styles.iter_mut().find(|s| s.same_entry(&style)) {
+            Some(registered) => *registered = style,
+            None => self.styles.push(style),
+        }
+    }
+
+    /// Returns the [`Requirements`] a document including content generated with this
+    /// preamble must satisfy (document class and fonts excluded).
+    ///
+    /// Returns an error if a package required by the preamble items conflicts with the
+    /// options of a package added by the user.
+    pub fn requirements(&self) -> Result<Requirements, PreambleError> {
+        self.resolve(vec![])
+    }
+
+    /// Checks that the packages required by the fonts (for the given [`LatexEngine`]), the
+    /// metadata and the preamble items do not conflict with the options of the packages
+    /// added by the user.
+    pub fn check_pkgs(&self, engine: LatexEngine) -> Result<(), PreambleError> {
+        self.resolve_for(engine).map(|_| ())
+    }
+
+    /// Resolves the preamble dependencies, including the packages required by the fonts for
+    /// the given [`LatexEngine`] and by the metadata.
+    fn resolve_for(&self, engine: LatexEngine) -> Result<Requirements, PreambleError> {
+        self.resolve(
+            self.fonts
+                .iter()
+                .flat_map(|fonts| fonts.pkgs(engine))
+                .chain(self.metadata.iter().flat_map(|metadata| metadata.pkgs()))
+                .collect(),
+        )
+    }
+
+    /// Resolves the preamble dependencies: `required` packages, packages required by the
+    /// items and `pgfplots` are merged with the user packages, which are then sorted in
+    /// loading order.
+    fn resolve(&self, required: Vec<Package>) -> Result<Requirements, PreambleError> {
+        let mut pkgs = self.pkgs.clone();
+        let required = required.into_iter().chain(
+            self.items
+                .iter()
+                .filter_map(|(_, item)| item.required_pkg()),
+        );
+        for pkg in required.chain([Package::from("pgfplots")]) {
+            match pkgs.iter_mut().find(|p| p.name() == pkg.name()) {
+                Some(loaded) => loaded.merge(&pkg)?,
+                None => pkgs.push(pkg),
+            }
+        }
+        // Stable sort: packages with the same load order keep their insertion order.
+        pkgs.sort_by_key(Package::load_order);
+
+        Ok(Requirements {
+            pkgs,
+            pgfcompat: self.get_pgfcompat(),
+            pgflibs: self.pgflibs.clone(),
+            tikzlibs: self.tikzlibs.clone(),
+            styles: self.styles.clone(),
+            items: self.items.clone(),
+        })
+    }
+}
+
+impl Preamble {
+    /// Returns a [`fmt::Display`] implementor rendering the preamble for the given
+    /// [`LatexEngine`].
+    ///
+    /// Returns an error if the packages required by the fonts, metadata or preamble items
+    /// conflict with the ones added by the user (see [`Preamble::check_pkgs`]).
+    pub fn display(&self, engine: LatexEngine) -> Result<impl fmt::Display + '_, PreambleError> {
+        Ok(PreambleDisplay {
+            preamble: self,
+            engine,
+            requirements: self.resolve_for(engine)?,
+        })
     }
 }
 
-impl fmt::Display for Preamble {
+/// [`Preamble`] rendered for a specific [`LatexEngine`], with its resolved dependencies.
+struct PreambleDisplay<'a> {
+    preamble: &'a Preamble,
+    engine: LatexEngine,
+    requirements: Requirements,
+}
+
+impl fmt::Display for PreambleDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(
-            f,
-            "\\documentclass{{standalone}}\n\\usepackage{{pgfplots}}\n{}",
-            self.pgfcompat
-        )?;
-
-        // Add PGFPlots libraries one per line.
-        for pgflib in &self.pgflibs {
-            writeln!(f, "{pgflib}")?;
+        let Self {
+            preamble,
+            engine,
+            requirements,
+        } = self;
+
+        match preamble.fonts.as_ref().and_then(Fonts::get_size) {
+            Some(size) => writeln!(f, "{}", preamble.class.with_option(size.to_string()))?,
+            None => writeln!(f, "{}", preamble.class)?,
         }
 
-        // Add LaTeX packages one per line.
-        for pkg in &self.pkgs {
-            writeln!(f, "{pkg}")?;
+        if preamble.reproducible {
+            for command in engine.reproducible_commands() {
+                writeln!(f, "{command}")?;
+            }
         }
 
-        Ok(())
+        let fonts = preamble.fonts.as_ref();
+        let metadata = preamble.metadata.as_ref();
+        requirements.fmt_items(f, Placement::BeforePackages)?;
+        requirements.fmt_pkgs(f)?;
+
+        // Add font selection commands one per line.
+        for command in fonts.iter().flat_map(|fonts| fonts.commands(*engine)) {
+            writeln!(f, "{command}")?;
+        }
+
+        if let Some(metadata) = metadata {
+            writeln!(f, "{metadata}")?;
+        }
+
+        requirements.fmt_items(f, Placement::AfterPackages)?;
+        requirements.fmt_settings(f)
     }
 }
 
@@ -142,30 +512,218 @@ mod test {
     fn preamble() {
         let preamble = Preamble::new()
             .pkg(Package::new("babel", &["italian"]))
-            .pkg("braket".into());
+            .unwrap()
+            .pkg("braket".into())
+            .unwrap();
         assert_eq!(
             r#"\documentclass{standalone}
 \usepackage{pgfplots}
-\pgfplotsset{compat=default}
-\usepackage{babel}[italian]
+\usepackage[italian]{babel}
 \usepackage{braket}
+\pgfplotsset{compat=default}
+"#,
+            preamble
+                .display(LatexEngine::default())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let mut preamble = Preamble::new()
+            .pkgs(&[
+                "hyperref".into(),
+                Package::new("babel", &["italian"]),
+                Package::new("xcolor", &["dvipsnames"]),
+                Package::new("babel", &["english", "italian"]),
+            ])
+            .unwrap()
+            .pgflibs(&[PgfPlotsLib::Statistics, PgfPlotsLib::Units])
+            .pgflib(PgfPlotsLib::Statistics)
+            .tikzlibs(&[TikzLib::Spy, TikzLib::Calc])
+            .tikzlib(TikzLib::Spy);
+        assert_eq!(
+            r#"\documentclass{standalone}
+\usepackage[dvipsnames]{xcolor}
+\usepackage{pgfplots}
+\usepackage[italian, english]{babel}
+\usepackage{hyperref}
+\pgfplotsset{compat=default}
+\usepgfplotslibrary{statistics}
+\usepgfplotslibrary{units}
+\usetikzlibrary{spy}
+\usetikzlibrary{calc}
+"#,
+            preamble
+                .display(LatexEngine::default())
+                .unwrap()
+                .to_string()
+        );
+
+        assert!(preamble
+            .add_pkg(Package::new("geometry", &["margin=1cm"]))
+            .is_ok());
+        assert!(preamble
+            .add_pkg(Package::new("geometry", &["margin=1cm"]))
+            .is_ok());
+        assert!(matches!(
+            preamble.add_pkg(Package::new("geometry", &["margin = 2cm"])),
+            Err(PreambleError::ConflictingOptions { .. })
+        ));
+    }
+
+    #[test]
+    fn styles() {
+        let mut preamble = Preamble::new();
+        preamble.add_style("highlight", ["blue"]);
+        preamble.add_every_style(EveryStyle::Axis, ["grid=major"]);
+        let highlight = preamble.add_style("highlight", ["thick", "red"]);
+        let cycle = preamble.add_cycle_list("mylist", ["blue", "red, dashed"]);
+        assert_eq!("highlight", highlight.to_string());
+        assert_eq!("mylist", cycle.to_string());
+        assert_eq!(
+            r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\pgfplotsset{compat=default}
+\pgfplotsset{
+	highlight/.style={thick, red},
+	every axis/.append style={grid=major},
+	cycle list/.define={mylist}{{blue}, {red, dashed}},
+}
+"#,
+            preamble
+                .display(LatexEngine::default())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn items() {
+        let preamble = Preamble::new()
+            .item(PreambleItem::Macro {
+                name: "vect".to_string(),
+                arity: 1,
+                definition: "\\mathbf{#1}".to_string(),
+            })
+            .unwrap()
+            .item(PreambleItem::Color {
+                name: "accent".to_string(),
+                model: ColorModel::Rgb,
+                spec: "0.1, 0.5, 0.9".to_string(),
+            })
+            .unwrap()
+            .item_at(
+                "\\PassOptionsToPackage{dvipsnames}{xcolor}".into(),
+                Placement::BeforePackages,
+            )
+            .unwrap()
+            .item(PreambleItem::Macro {
+                name: "\\vect".to_string(),
+                arity: 1,
+                definition: "\\vec{#1}".to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            r#"\documentclass{standalone}
+\PassOptionsToPackage{dvipsnames}{xcolor}
+\usepackage{xcolor}
+\usepackage{pgfplots}
+\newcommand{\vect}[1]{\vec{#1}}
+\definecolor{accent}{rgb}{0.1, 0.5, 0.9}
+\pgfplotsset{compat=default}
+"#,
+            preamble
+                .display(LatexEngine::default())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn required_conflict() {
+        let preamble = Preamble::new()
+            .pkg(Package::new("hyperref", &["colorlinks=true"]))
+            .unwrap();
+        assert!(preamble.check_pkgs(LatexEngine::PdfLatex).is_ok());
+        assert!(matches!(
+            preamble.resolve(vec![Package::new("hyperref", &["colorlinks=false"])]),
+            Err(PreambleError::ConflictingOptions { .. })
+        ));
+    }
+
+    #[test]
+    fn metadata() {
+        let mut preamble = Preamble::new().metadata(Metadata::new().title("Results"));
+        preamble.set_reproducible(true);
+        assert_eq!(
+            r#"\documentclass{standalone}
+\pdftrailerid{}
+\pdfsuppressptexinfo=-1
+\usepackage{pgfplots}
+\usepackage{hyperref}
+\usepackage{hyperxmp}
+\hypersetup{pdftitle={Results}}
+\pgfplotsset{compat=default}
+"#,
+            preamble.display(LatexEngine::PdfLatex).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn pgfcompat() {
+        let mut preamble = Preamble::new();
+        preamble.require_pgfcompat(PgfPlotsCompat::V1_7);
+        preamble.require_pgfcompat(PgfPlotsCompat::V1_3);
+        assert_eq!(PgfPlotsCompat::V1_7, preamble.get_pgfcompat());
+        assert!(preamble.check_pgfcompat().is_ok());
+
+        preamble.set_pgfcompat(PgfPlotsCompat::V1_5);
+        assert_eq!(PgfPlotsCompat::V1_5, preamble.get_pgfcompat());
+        assert!(matches!(
+            preamble.check_pgfcompat(),
+            Err(PgfPlotsCompatError::TooLow {
+                version: PgfPlotsCompat::V1_5,
+                required: PgfPlotsCompat::V1_7
+            })
+        ));
+
+        preamble.set_pgfcompat_version("1.18").unwrap();
+        assert!(preamble.check_pgfcompat().is_ok());
+    }
+
+    #[test]
+    fn document_class() {
+        let preamble =
+            Preamble::new().document_class(DocumentClass::Article(vec![ArticleOption::PaperSize(
+                PaperSize::Letter,
+            )]));
+        assert_eq!(
+            r#"\documentclass[letterpaper]{article}
+\usepackage{pgfplots}
+\pgfplotsset{compat=default}
 "#,
-            preamble.to_string()
+            preamble
+                .display(LatexEngine::default())
+                .unwrap()
+                .to_string()
         );
     }
 
     #[test]
     fn package() {
         let package = Package::new("babel", &["italian"]);
-        assert_eq!("\\usepackage{babel}[italian]", package.to_string());
+        assert_eq!("\\usepackage[italian]{babel}", package.to_string());
 
         let mut package: Package = "babel".into();
         assert_eq!("\\usepackage{babel}", package.to_string());
 
         package.add_option("italian");
-        assert_eq!("\\usepackage{babel}[italian]", package.to_string());
+        assert_eq!("\\usepackage[italian]{babel}", package.to_string());
 
         package.add_option("english");
-        assert_eq!("\\usepackage{babel}[italian, english]", package.to_string());
+        package.add_option("italian");
+        assert_eq!("\\usepackage[italian, english]{babel}", package.to_string());
     }
 }
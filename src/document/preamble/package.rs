@@ -1,11 +1,12 @@
+use crate::error::PreambleError;
 use std::fmt;
 
 /// LaTeX pacakges used to compile the standalone document.
 ///
 /// ```text
-/// \usepackage{name}[options]
+/// \usepackage[options]{name}
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Package {
     /// LaTeX package name.
     name: String,
@@ -15,12 +16,12 @@ pub struct Package {
 
 impl fmt::Display for Package {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\\usepackage{{{}}}", self.name)?;
+        write!(f, "\\usepackage")?;
         if !self.options.is_empty() {
             write!(f, "[{}]", self.options.join(", "))?;
         }
 
-        Ok(())
+        write!(f, "{{{}}}", self.name)
     }
 }
 
@@ -39,14 +40,106 @@ where
 impl Package {
     /// Construct a new [`Package`].
     pub fn new(name: &str, options: &[&str]) -> Self {
-        Self {
-            name: name.to_string(),
-            options: options.iter().map(|option| option.to_string()).collect(),
+        let mut package = Self::from(name);
+        for option in options {
+            package.add_option(option);
         }
+
+        package
     }
 
-    /// Add an option to the LaTeX package import.
+    /// Returns the LaTeX package name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Add an option to the LaTeX package import, unless already present.
+    ///
+    /// The white space around the key and the value of `key=value` options is trimmed, e.g.
+    /// `margin = 1cm` is the same option as `margin=1cm`.
     pub fn add_option(&mut self, option: &str) {
-        self.options.push(option.to_string());
+        let option = match option.split_once('=') {
+            Some((key, value)) => format!("{}={}", key.trim(), value.trim()),
+            None => option.trim().to_string(),
+        };
+        if !self.options.contains(&option) {
+            self.options.push(option);
+        }
+    }
+
+    /// Merge the options of `other` (which must be the same package) into `self`.
+    ///
+    /// Returns an error if `other` sets a `key=value` option to a different value than the
+    /// one already set.
+    pub(crate) fn merge(&mut self, other: &Package) -> Result<(), PreambleError> {
+        debug_assert_eq!(self.name, other.name);
+
+        // Options are trimmed when added: equal options are written the same way.
+        for option in &other.options {
+            if let Some(conflicting) = self.options.iter().find(|opt| {
+                *opt != option
+                    && matches!(
+                        (opt.split_once('='), option.split_once('=')),
+                        (Some((key, _)), Some((other_key, _))) if key == other_key
+                    )
+            }) {
+                return Err(PreambleError::ConflictingOptions {
+                    package: self.name.clone(),
+                    option: conflicting.clone(),
+                    conflicting: option.clone(),
+                });
+            }
+
+            self.add_option(option);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [`LoadOrder`] of the package: packages are sorted according to it in the
+    /// document preamble.
+    pub(crate) fn load_order(&self) -> LoadOrder {
+        match self.name.as_str() {
+            "inputenc" | "fontenc" | "xcolor" => LoadOrder::BeforePgfPlots,
+            "pgfplots" => LoadOrder::PgfPlots,
+            "hyperref" => LoadOrder::Hyperref,
+            "cleveref" | "hyperxmp" => LoadOrder::AfterHyperref,
+            _ => LoadOrder::Default,
+        }
+    }
+}
+
+/// Position of a [`Package`] in the document preamble.
+///
+/// Some packages must be loaded before `pgfplots` (e.g. `xcolor`, whose options would
+/// otherwise clash with the ones set by Ti*k*Z), while `hyperref` must be loaded as late as
+/// possible, followed only by the packages which depend on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum LoadOrder {
+    BeforePgfPlots,
+    PgfPlots,
+    Default,
+    Hyperref,
+    AfterHyperref,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn merge() {
+        let mut package = Package::new("geometry", &["margin=1cm"]);
+        package
+            .merge(&Package::new("geometry", &[" margin = 1cm", "a4paper"]))
+            .unwrap();
+        assert_eq!(
+            "\\usepackage[margin=1cm, a4paper]{geometry}",
+            package.to_string()
+        );
+        assert!(matches!(
+            package.merge(&Package::new("geometry", &["margin=2cm"])),
+            Err(PreambleError::ConflictingOptions { .. })
+        ));
     }
 }
@@ -1,7 +1,7 @@
 use std::fmt;
 
 /// PGFPlots library.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum PgfPlotsLib {
     /// Custom library not yet implemented.
@@ -17,17 +17,21 @@ pub enum PgfPlotsLib {
     /// A library which allows to fill the *area between* two arbitrary named plots.
     /// It can also identify segments of the intersections and fill the segments individually.
     FillBetween,
+    /// A library which allows to arrange multiple axes in a grid (`groupplot` environment).
+    GroupPlots,
+    /// A library which provides polar axes (`polaraxis` environment).
+    Polar,
+    /// A library which provides Smith charts (`smithchart` environment).
+    SmithChart,
     /// A library which provides plot handlers for statistics
     /// (e.g. *hisograms*, *box-plots*, etc.).
     Statistics,
+    /// A library which provides ternary diagrams (`ternaryaxis` environment).
+    Ternary,
     /// A library which allows to use automatic typesetting of *units* in labels.
     Units,
     // TODO: follow unimplemented variants.
-    // GroupPlots, FIXME: this requires the introduction of the `groupplot` environment.
     // PatchPlots,
-    // Polar, FIXME: this requires the introduction of the `polaraxis` environment.
-    // SmithChart, FIXME: this requires the introduction of the `smithchart` environment.
-    // Ternary, FIXME: this requires the introduction of the `ternary` environment.
 }
 
 impl fmt::Display for PgfPlotsLib {
@@ -41,7 +45,11 @@ impl fmt::Display for PgfPlotsLib {
                 Self::DatePlot => "dateplot",
                 Self::External => "external",
                 Self::FillBetween => "fillbetween",
+                Self::GroupPlots => "groupplots",
+                Self::Polar => "polar",
+                Self::SmithChart => "smithchart",
                 Self::Statistics => "statistics",
+                Self::Ternary => "ternary",
                 Self::Units => "units",
             }
         )
@@ -60,8 +68,21 @@ mod test {
 
     #[test]
     fn pgfplotslib() {
-        assert_eq!("\\usepgfplotslibrary{{polar}}", PgfPlotsLib::from("polar").to_string());
-        assert_eq!("\\usepgfplotslibrary{{units}}", PgfPlotsLib::Statistics.to_string());
-        assert_eq!("\\usepgfplotslibrary{{statistics}}", PgfPlotsLib::Statistics.to_string());
+        assert_eq!(
+            "\\usepgfplotslibrary{polar}",
+            PgfPlotsLib::from("polar").to_string()
+        );
+        assert_eq!(
+            "\\usepgfplotslibrary{units}",
+            PgfPlotsLib::Units.to_string()
+        );
+        assert_eq!(
+            "\\usepgfplotslibrary{statistics}",
+            PgfPlotsLib::Statistics.to_string()
+        );
+        assert_eq!(
+            "\\usepgfplotslibrary{groupplots}",
+            PgfPlotsLib::GroupPlots.to_string()
+        );
     }
 }
@@ -0,0 +1,131 @@
+use super::{
+    Package, PgfPlotsCompat, PgfPlotsLib, PgfPlotsStyle, Placement, PreambleItem, TikzLib,
+};
+use std::fmt;
+
+/// Preamble requirements of a document fragment, i.e. everything the preamble of the
+/// document including the fragment must provide for it to compile.
+///
+/// The [`fmt::Display`] implementation renders the requirements as a preamble snippet:
+///
+/// ```text
+/// \usepackage{pgfplots}
+/// \newcommand{\R}{\mathbb{R}}
+/// \pgfplotsset{compat=1.7}
+/// \usepgfplotslibrary{statistics}
+/// \usetikzlibrary{spy}
+/// \pgfplotsset{
+///     highlight/.style={thick, red},
+/// }
+/// ```
+///
+/// Document class and fonts are left to the including document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Requirements {
+    /// LaTeX packages, `pgfplots` included, in loading order.
+    pub(super) pkgs: Vec<Package>,
+    /// PGFPlots compatibility layer.
+    pub(super) pgfcompat: PgfPlotsCompat,
+    /// PGFPlots libraries.
+    pub(super) pgflibs: Vec<PgfPlotsLib>,
+    /// Ti*k*Z libraries.
+    pub(super) tikzlibs: Vec<TikzLib>,
+    /// Global PGFPlots style registry.
+    pub(super) styles: Vec<PgfPlotsStyle>,
+    /// Macros, colors and raw lines, with their placement.
+    pub(super) items: Vec<(Placement, PreambleItem)>,
+}
+
+impl fmt::Display for Requirements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_items(f, Placement::BeforePackages)?;
+        self.fmt_pkgs(f)?;
+        self.fmt_items(f, Placement::AfterPackages)?;
+        self.fmt_settings(f)
+    }
+}
+
+impl Requirements {
+    /// Returns the required LaTeX packages, `pgfplots` included, in loading order.
+    pub fn pkgs(&self) -> &[Package] {
+        &self.pkgs
+    }
+
+    /// Returns the required PGFPlots compatibility layer.
+    pub fn pgfcompat(&self) -> PgfPlotsCompat {
+        self.pgfcompat
+    }
+
+    /// Returns the required PGFPlots libraries.
+    pub fn pgflibs(&self) -> &[PgfPlotsLib] {
+        &self.pgflibs
+    }
+
+    /// Returns the required Ti*k*Z libraries.
+    pub fn tikzlibs(&self) -> &[TikzLib] {
+        &self.tikzlibs
+    }
+
+    /// Returns the required global PGFPlots styles.
+    pub fn styles(&self) -> &[PgfPlotsStyle] {
+        &self.styles
+    }
+
+    /// Returns the required [`PreambleItem`]s at the given [`Placement`].
+    pub fn items(&self, placement: Placement) -> Vec<&PreambleItem> {
+        self.items
+            .iter()
+            .filter(|(p, _)| *p == placement)
+            .map(|(_, item)| item)
+            .collect()
+    }
+
+    /// Writes the items at the given [`Placement`], one per line.
+    pub(super) fn fmt_items(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        placement: Placement,
+    ) -> fmt::Result {
+        for item in self.items(placement) {
+            writeln!(f, "{item}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the packages, one per line.
+    pub(super) fn fmt_pkgs(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for pkg in &self.pkgs {
+            writeln!(f, "{pkg}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the PGFPlots settings (compatibility layer, libraries and style registry),
+    /// which must follow the packages.
+    pub(super) fn fmt_settings(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.pgfcompat)?;
+
+        // Add PGFPlots libraries one per line.
+        for pgflib in &self.pgflibs {
+            writeln!(f, "{pgflib}")?;
+        }
+
+        // Add Ti*k*Z libraries one per line.
+        for tikzlib in &self.tikzlibs {
+            writeln!(f, "{tikzlib}")?;
+        }
+
+        // Add the style registry, one entry per line.
+        if !self.styles.is_empty() {
+            writeln!(f, "\\pgfplotsset{{")?;
+            for style in &self.styles {
+                writeln!(f, "\t{style},")?;
+            }
+            writeln!(f, "}}")?;
+        }
+
+        Ok(())
+    }
+}
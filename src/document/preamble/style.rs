@@ -0,0 +1,254 @@
+use std::fmt;
+
+/// Predefined PGFPlots styles which can be extended through the style registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EveryStyle {
+    /// Custom predefined style not yet implemented, e.g. `every axis z label`.
+    Custom(String),
+    /// Style applied to every axis environment (`every axis`).
+    Axis,
+    /// Style applied to every plot inside an axis (`every axis plot`).
+    AxisPlot,
+    /// Style applied to every legend (`every axis legend`).
+    AxisLegend,
+    /// Style applied to every axis title (`every axis title`).
+    AxisTitle,
+    /// Style applied to every `x` axis label (`every axis x label`).
+    AxisXLabel,
+    /// Style applied to every `y` axis label (`every axis y label`).
+    AxisYLabel,
+    /// Style applied to every tick label (`every tick label`).
+    TickLabel,
+}
+
+impl fmt::Display for EveryStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(style) => write!(f, "{style}"),
+            Self::Axis => write!(f, "every axis"),
+            Self::AxisPlot => write!(f, "every axis plot"),
+            Self::AxisLegend => write!(f, "every axis legend"),
+            Self::AxisTitle => write!(f, "every axis title"),
+            Self::AxisXLabel => write!(f, "every axis x label"),
+            Self::AxisYLabel => write!(f, "every axis y label"),
+            Self::TickLabel => write!(f, "every tick label"),
+        }
+    }
+}
+
+/// Entry of the global style registry, written in the document preamble as:
+///
+/// ```text
+/// \pgfplotsset{
+///     entry,
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PgfPlotsStyle {
+    /// Named style (`name/.style={options}`), referenced through a [`StyleRef`].
+    Style { name: String, options: Vec<String> },
+    /// Options appended to a predefined style (`every axis/.append style={options}`).
+    Every {
+        target: EveryStyle,
+        options: Vec<String>,
+    },
+    /// Named cycle list (`cycle list/.define={name}{...}`), referenced through a
+    /// [`CycleListRef`]. Each entry is the style of one plot in the cycle.
+    CycleList { name: String, entries: Vec<String> },
+    /// Named colormap (`colormap/name/.style={colormap={name}{...}}`), referenced through a
+    /// [`ColormapRef`]. Like the predefined colormaps, it is defined as a style so that it
+    /// only applies to the axes selecting it, instead of becoming the default colormap.
+    Colormap { name: String, stops: Vec<ColorStop> },
+}
+
+impl fmt::Display for PgfPlotsStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Style { name, options } => {
+                write!(f, "{name}/.style={{{}}}", options.join(", "))
+            }
+            Self::Every { target, options } => {
+                write!(f, "{target}/.append style={{{}}}", options.join(", "))
+            }
+            Self::CycleList { name, entries } => write!(
+                f,
+                "cycle list/.define={{{name}}}{{{}}}",
+                entries
+                    .iter()
+                    .map(|entry| format!("{{{entry}}}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Colormap { name, stops } => write!(
+                f,
+                "colormap/{name}/.style={{colormap={{{name}}}{{{}}}}}",
+                stops
+                    .iter()
+                    .map(ColorStop::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+        }
+    }
+}
+
+impl PgfPlotsStyle {
+    /// Returns whether `self` and `other` define the same registry entry, in which case
+    /// the latter replaces the former.
+    pub(crate) fn same_entry(&self, other: &PgfPlotsStyle) -> bool {
+        match (self, other) {
+            (Self::Style { name, .. }, Self::Style { name: other, .. }) => name == other,
+            (Self::Every { target, .. }, Self::Every { target: other, .. }) => target == other,
+            (Self::CycleList { name, .. }, Self::CycleList { name: other, .. }) => name == other,
+            (Self::Colormap { name, .. }, Self::Colormap { name: other, .. }) => name == other,
+            _ => false,
+        }
+    }
+}
+
+/// Handle to a named style registered in the document preamble.
+///
+/// A [`StyleRef`] can only be obtained by registering the style (e.g. with
+/// [`crate::document::Document::add_style`]), so that a reference to an undefined style
+/// is caught at compile time rather than by the LaTeX engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleRef {
+    name: String,
+}
+
+impl fmt::Display for StyleRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl StyleRef {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Handle to a named cycle list registered in the document preamble.
+///
+/// A [`CycleListRef`] can only be obtained by registering the cycle list (e.g. with
+/// [`crate::document::Document::add_cycle_list`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleListRef {
+    name: String,
+}
+
+impl fmt::Display for CycleListRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl CycleListRef {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Color of a colormap registered with [`crate::document::Document::add_colormap`] at the
+/// given position.
+///
+/// Positions are relative: the colormap spans from the smallest to the largest one, and the
+/// colors are interpolated linearly in between.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// Position of the color in the colormap.
+    pub position: f64,
+    /// RGB components of the color, between `0` and `255`.
+    pub rgb: [u8; 3],
+}
+
+impl fmt::Display for ColorStop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b] = self.rgb;
+        write!(f, "rgb255({}cm)=({r},{g},{b})", self.position)
+    }
+}
+
+impl From<(f64, [u8; 3])> for ColorStop {
+    /// Conversion from a `(position, [r, g, b])` tuple into a color stop.
+    fn from((position, rgb): (f64, [u8; 3])) -> Self {
+        Self { position, rgb }
+    }
+}
+
+impl ColorStop {
+    /// Creates a new color stop.
+    pub fn new(position: f64, rgb: [u8; 3]) -> Self {
+        Self { position, rgb }
+    }
+}
+
+/// Handle to a named colormap registered in the document preamble.
+///
+/// A [`ColormapRef`] can only be obtained by registering the colormap (e.g. with
+/// [`crate::document::Document::add_colormap`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColormapRef {
+    name: String,
+}
+
+impl fmt::Display for ColormapRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl ColormapRef {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pgfplots_style() {
+        assert_eq!(
+            "highlight/.style={thick, red}",
+            PgfPlotsStyle::Style {
+                name: "highlight".to_string(),
+                options: vec!["thick".to_string(), "red".to_string()],
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "every axis/.append style={font=\\small}",
+            PgfPlotsStyle::Every {
+                target: EveryStyle::Axis,
+                options: vec!["font=\\small".to_string()],
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "cycle list/.define={mylist}{{blue, mark=*}, {red, dashed}}",
+            PgfPlotsStyle::CycleList {
+                name: "mylist".to_string(),
+                entries: vec!["blue, mark=*".to_string(), "red, dashed".to_string()],
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "colormap/heat/.style={colormap={heat}{rgb255(0cm)=(0,0,255); rgb255(1cm)=(255,0,0)}}",
+            PgfPlotsStyle::Colormap {
+                name: "heat".to_string(),
+                stops: vec![(0.0, [0, 0, 255]).into(), ColorStop::new(1.0, [255, 0, 0])],
+            }
+            .to_string()
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+/// Ti*k*Z library.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TikzLib {
+    /// Custom library not yet implemented.
+    /// This allows to specify Ti*k*Z libraries not included in the enum.
+    Custom(String),
+    /// A library which defines the (deprecated) arrow tips, e.g. `stealth` or `latex`.
+    Arrows,
+    /// A library which defines the configurable arrow tips, e.g. `Stealth` or `Latex`.
+    ArrowsMeta,
+    /// A library which allows to draw on background layers (e.g. `on background layer`).
+    Backgrounds,
+    /// A library which allows coordinate computations, e.g. `($(a)!0.5!(b)$)`.
+    Calc,
+    /// A library which defines decorations that change the path (e.g. `zigzag`, `snake`).
+    DecorationsPathMorphing,
+    /// A library which allows placing marks (e.g. arrows) along a path.
+    DecorationsMarkings,
+    /// A library which allows to compute intersections of paths.
+    Intersections,
+    /// A library which allows to fit a node around a set of coordinates.
+    Fit,
+    /// A library which defines the (deprecated) hatching patterns, e.g. `north east lines`.
+    Patterns,
+    /// A library which defines the configurable hatching patterns, e.g. `Lines`.
+    PatternsMeta,
+    /// A library which allows relative placement of nodes, e.g. `right=of a`.
+    Positioning,
+    /// A library which defines geometric node shapes, e.g. `diamond` or `star`.
+    ShapesGeometric,
+    /// A library which allows to magnify parts of a picture.
+    Spy,
+}
+
+impl fmt::Display for TikzLib {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\\usetikzlibrary{{{}}}",
+            match self {
+                Self::Custom(lib) => lib,
+                Self::Arrows => "arrows",
+                Self::ArrowsMeta => "arrows.meta",
+                Self::Backgrounds => "backgrounds",
+                Self::Calc => "calc",
+                Self::DecorationsPathMorphing => "decorations.pathmorphing",
+                Self::DecorationsMarkings => "decorations.markings",
+                Self::Intersections => "intersections",
+                Self::Fit => "fit",
+                Self::Patterns => "patterns",
+                Self::PatternsMeta => "patterns.meta",
+                Self::Positioning => "positioning",
+                Self::ShapesGeometric => "shapes.geometric",
+                Self::Spy => "spy",
+            }
+        )
+    }
+}
+
+impl From<&str> for TikzLib {
+    fn from(lib: &str) -> Self {
+        Self::Custom(lib.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tikzlib() {
+        assert_eq!(
+            "\\usetikzlibrary{shadows}",
+            TikzLib::from("shadows").to_string()
+        );
+        assert_eq!(
+            "\\usetikzlibrary{arrows.meta}",
+            TikzLib::ArrowsMeta.to_string()
+        );
+        assert_eq!("\\usetikzlibrary{spy}", TikzLib::Spy.to_string());
+    }
+}
@@ -0,0 +1,219 @@
+use super::Ticks;
+use crate::document::preamble::ColormapRef;
+use std::fmt;
+
+/// Colormap used to map the point meta values of the plots (e.g. the `z` coordinates of a
+/// surface) to colors, and drawn by the [`Colorbar`].
+///
+/// The [`Colormap::Custom`] variant is provided to use colormaps that have not been
+/// implemented, and will be written verbatim in the options of the axis (e.g.
+/// `colormap/PuBu` from the `colormaps` PGFPlots library).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Colormap {
+    /// Custom colormap option that has not been implemented.
+    Custom(String),
+    /// Black, red, yellow, white (PGFPlots default).
+    Hot,
+    /// Black, red, yellow, white, with a darker start.
+    Hot2,
+    /// Dark blue, blue, cyan, yellow, red, dark red (MATLAB `jet`).
+    Jet,
+    /// Black to white.
+    BlackWhite,
+    /// Blue to red, through white.
+    BlueRed,
+    /// White, cyan, blue.
+    Cool,
+    /// Green to yellow.
+    GreenYellow,
+    /// Red to yellow.
+    RedYellow,
+    /// Purple to white.
+    Violet,
+    /// Perceptually uniform dark blue, green, yellow colormap, readable in grayscale and by
+    /// color blind readers.
+    Viridis,
+    /// Colormap registered with [`crate::document::Document::add_colormap`].
+    Registered(ColormapRef),
+}
+
+impl fmt::Display for Colormap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::Hot => write!(f, "colormap/hot"),
+            Self::Hot2 => write!(f, "colormap/hot2"),
+            Self::Jet => write!(f, "colormap/jet"),
+            Self::BlackWhite => write!(f, "colormap/blackwhite"),
+            Self::BlueRed => write!(f, "colormap/bluered"),
+            Self::Cool => write!(f, "colormap/cool"),
+            Self::GreenYellow => write!(f, "colormap/greenyellow"),
+            Self::RedYellow => write!(f, "colormap/redyellow"),
+            Self::Violet => write!(f, "colormap/violet"),
+            Self::Viridis => write!(f, "colormap/viridis"),
+            Self::Registered(colormap) => write!(f, "colormap/{colormap}"),
+        }
+    }
+}
+
+/// Orientation of a [`Colorbar`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorbarOrientation {
+    /// Vertical colorbar, on the right of the axis.
+    #[default]
+    Vertical,
+    /// Horizontal colorbar, below the axis.
+    Horizontal,
+}
+
+/// Rendering of the colors of a [`Colorbar`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorbarMode {
+    /// Colors interpolated continuously.
+    #[default]
+    Continuous,
+    /// Colormap sampled at the given number of points, with the colors interpolated
+    /// between them.
+    Sampled(usize),
+    /// Piecewise constant colors, one per interval of the colormap. This also applies to
+    /// the colors of the plots of the axis.
+    Discrete,
+}
+
+/// Colorbar of an [`super::Axis`], drawing its [`Colormap`] next to the axis together with
+/// the range of the point meta values (see [`super::AxisOption::PointMetaMin`] and
+/// [`super::AxisOption::PointMetaMax`]):
+///
+/// ```text
+/// colorbar horizontal, colorbar style={xlabel={label}, xtick={ticks}}
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::tikzpicture::axis::{
+///     colormap::{Colorbar, ColorbarOrientation, Colormap},
+///     Axis, AxisOption,
+/// };
+///
+/// let axis = Axis::new()
+///     .option(AxisOption::Colormap(Colormap::Viridis))
+///     .option(AxisOption::Colorbar(
+///         Colorbar::new()
+///             .orientation(ColorbarOrientation::Horizontal)
+///             .label("Temperature~[K]")
+///             .ticks([250.0, 300.0, 350.0]),
+///     ))
+///     .option(AxisOption::PointMetaMin(250.0))
+///     .option(AxisOption::PointMetaMax(350.0));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Colorbar {
+    orientation: ColorbarOrientation,
+    mode: ColorbarMode,
+    label: Option<String>,
+    ticks: Option<Ticks>,
+}
+
+impl fmt::Display for Colorbar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            ColorbarMode::Sampled(_) => write!(f, "colorbar sampled")?,
+            _ => write!(f, "colorbar")?,
+        }
+        if self.orientation == ColorbarOrientation::Horizontal {
+            write!(f, ", colorbar horizontal")?;
+        }
+        if self.mode == ColorbarMode::Discrete {
+            write!(f, ", colormap access=piecewise constant")?;
+        }
+
+        // The colorbar is an axis itself: its values are on the `y` axis when vertical, on
+        // the `x` axis when horizontal.
+        let dimension = match self.orientation {
+            ColorbarOrientation::Vertical => 'y',
+            ColorbarOrientation::Horizontal => 'x',
+        };
+        let mut style = vec![];
+        if let ColorbarMode::Sampled(samples) = self.mode {
+            style.push(format!("samples={samples}"));
+        }
+        if let Some(label) = &self.label {
+            style.push(format!("{dimension}label={{{label}}}"));
+        }
+        if let Some(ticks) = &self.ticks {
+            style.push(format!("{dimension}tick={{{ticks}}}"));
+        }
+        if !style.is_empty() {
+            write!(f, ", colorbar style={{{}}}", style.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Colorbar {
+    /// Creates a new, vertical and continuous colorbar.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the [`ColorbarOrientation`].
+    pub fn orientation(mut self, orientation: ColorbarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the [`ColorbarMode`].
+    pub fn mode(mut self, mode: ColorbarMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the label of the colorbar.
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Set the ticks of the colorbar.
+    pub fn ticks<T>(mut self, ticks: T) -> Self
+    where
+        T: Into<Ticks>,
+    {
+        self.ticks = Some(ticks.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn colorbar() {
+        assert_eq!("colorbar", Colorbar::new().to_string());
+        assert_eq!(
+            "colorbar sampled, colorbar horizontal, colorbar style={samples=10, xlabel={T}, xtick={0, 1}}",
+            Colorbar::new()
+                .orientation(ColorbarOrientation::Horizontal)
+                .mode(ColorbarMode::Sampled(10))
+                .label("T")
+                .ticks([0.0, 1.0])
+                .to_string()
+        );
+        assert_eq!(
+            "colorbar, colormap access=piecewise constant, colorbar style={ylabel={T}}",
+            Colorbar::new()
+                .mode(ColorbarMode::Discrete)
+                .label("T")
+                .to_string()
+        );
+        assert_eq!("colormap/jet", Colormap::Jet.to_string());
+        assert_eq!(
+            "colormap/heat",
+            Colormap::Registered(ColormapRef::new("heat")).to_string()
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::document::tikzpicture::Anchor;
+use std::fmt;
+
+/// Control the position of the legend of an [`super::Axis`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum LegendPos {
+    /// Inside the axis, in the top right corner.
+    NorthEast,
+    /// Inside the axis, in the top left corner.
+    NorthWest,
+    /// Inside the axis, in the bottom right corner.
+    SouthEast,
+    /// Inside the axis, in the bottom left corner.
+    SouthWest,
+    /// Outside the axis, aligned with its top right corner.
+    OuterNorthEast,
+    /// Outside the axis, on its right, vertically centered.
+    OuterEast,
+    /// Outside the axis, above it, horizontally centered.
+    OuterNorth,
+    /// Outside the axis, right below its `x` tick labels, horizontally centered.
+    OuterSouth,
+    /// Legend `anchor` placed at `(x, y)`, as fractions of the axis width and height
+    /// (`(0, 0)` is the bottom left corner of the axis).
+    At { x: f64, y: f64, anchor: Anchor },
+}
+
+impl fmt::Display for LegendPos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, y, anchor) = match self {
+            Self::NorthEast => return write!(f, "legend pos=north east"),
+            Self::NorthWest => return write!(f, "legend pos=north west"),
+            Self::SouthEast => return write!(f, "legend pos=south east"),
+            Self::SouthWest => return write!(f, "legend pos=south west"),
+            Self::OuterNorthEast => return write!(f, "legend pos=outer north east"),
+            Self::OuterEast => (1.03, 0.5, &Anchor::West),
+            Self::OuterNorth => (0.5, 1.03, &Anchor::South),
+            Self::OuterSouth => {
+                // The tick labels height is only known by PGFPlots.
+                return write!(
+                    f,
+                    "legend style={{at={{(xticklabel cs:0.5)}}, anchor={}}}",
+                    Anchor::North
+                );
+            }
+            Self::At { x, y, anchor } => (*x, *y, anchor),
+        };
+
+        write!(f, "legend style={{at={{({x},{y})}}, anchor={anchor}}}")
+    }
+}
+
+/// Legend entry that does not correspond to a plot, drawn with the given `style` (e.g.
+/// `only marks, mark=*, red`):
+///
+/// ```text
+/// \addlegendimage{style}
+/// \addlegendentry{entry}
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::tikzpicture::axis::{legend::LegendImage, Axis};
+///
+/// let axis = Axis::new().plot(LegendImage::new("dashed, gray", "threshold").into());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegendImage {
+    style: String,
+    entry: String,
+}
+
+impl fmt::Display for LegendImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t\\addlegendimage{{{}}}", self.style)?;
+        write!(f, "\t\\addlegendentry{{{}}}", self.entry)
+    }
+}
+
+impl LegendImage {
+    /// Creates a new legend entry drawn with the given `style`.
+    pub fn new(style: &str, entry: &str) -> Self {
+        Self {
+            style: style.to_string(),
+            entry: entry.to_string(),
+        }
+    }
+}
+
+/// Writes the legend entry of a plot, if any, on the line following the plot.
+pub(crate) fn fmt_entry(f: &mut fmt::Formatter<'_>, entry: Option<&str>) -> fmt::Result {
+    match entry {
+        Some(entry) => write!(f, "\n\t\\addlegendentry{{{entry}}}"),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn legend_pos() {
+        assert_eq!("legend pos=north west", LegendPos::NorthWest.to_string());
+        assert_eq!(
+            "legend style={at={(xticklabel cs:0.5)}, anchor=north}",
+            LegendPos::OuterSouth.to_string()
+        );
+        assert_eq!(
+            "\t\\addlegendimage{red}\n\t\\addlegendentry{data}",
+            LegendImage::new("red", "data").to_string()
+        );
+    }
+}
@@ -1,14 +1,27 @@
+pub mod colormap;
+pub mod legend;
 pub mod plot;
+pub mod ticks;
 
 use crate::{
     document::{
-        preamble::PgfPlotsLib,
-        tikzpicture::axis::plot::{bidimensional::Plot2D, Plot},
+        length::Length,
+        preamble::{CycleListRef, PgfPlotsCompat, PgfPlotsLib, StyleRef, TikzLib},
+        tikzpicture::{
+            axis::plot::{bidimensional::Plot2D, Plot},
+            path::Point,
+            spy::{Spy, ZoomInset},
+            Anchor,
+        },
     },
+    error::CoordinateError,
     libs::statistics::histogram::Histogram,
 };
+use colormap::{Colorbar, Colormap};
 use itertools::Itertools;
+use legend::LegendPos;
 use std::fmt;
+use ticks::{NumberFormat, ScaledTicks, TickAlign, TickPos};
 
 /// PGFPlots options passed to the [`Axis`] environment.
 ///
@@ -51,6 +64,55 @@ pub enum AxisOption {
     XTick(Ticks),
     /// Control the `y` ticks manually (`ytick` option).
     YTick(Ticks),
+    /// Control the `z` ticks manually (`ztick` option).
+    ZTick(Ticks),
+    /// Control the number of minor ticks between two major ticks of every axis.
+    MinorTickNum(usize),
+    /// Control the `x` minor ticks manually (`minor xtick` option).
+    MinorXTick(Ticks),
+    /// Control the `y` minor ticks manually (`minor ytick` option).
+    MinorYTick(Ticks),
+    /// Control the `z` minor ticks manually (`minor ztick` option).
+    MinorZTick(Ticks),
+    /// Control on which side of every axis the ticks are drawn.
+    TickPos(TickPos),
+    /// Control on which side of the `x` axis the ticks are drawn.
+    XTickPos(TickPos),
+    /// Control on which side of the `y` axis the ticks are drawn.
+    YTickPos(TickPos),
+    /// Control the alignment of the ticks with the axis lines.
+    TickAlign(TickAlign),
+    /// Append custom keys to the style of the tick labels of every axis, e.g. `font=\small`.
+    /// Unlike most variants, multiple styles can be applied to the same axis.
+    TickLabelStyle(String),
+    /// Append custom keys to the style of the `x` tick labels. Multiple styles can be applied
+    /// to the same axis.
+    XTickLabelStyle(String),
+    /// Append custom keys to the style of the `y` tick labels. Multiple styles can be applied
+    /// to the same axis.
+    YTickLabelStyle(String),
+    /// Rotate the `x` tick labels by the given angle, in degrees.
+    XTickLabelRotate(f64),
+    /// Rotate the `y` tick labels by the given angle, in degrees.
+    YTickLabelRotate(f64),
+    /// Control the format of the tick labels of every axis.
+    NumberFormat(NumberFormat),
+    /// Control the format of the `x` tick labels.
+    XNumberFormat(NumberFormat),
+    /// Control the format of the `y` tick labels.
+    YNumberFormat(NumberFormat),
+    /// Control the scaling of the ticks of every axis.
+    ScaledTicks(ScaledTicks),
+    /// Control the scaling of the `x` ticks.
+    ScaledXTicks(ScaledTicks),
+    /// Control the scaling of the `y` ticks.
+    ScaledYTicks(ScaledTicks),
+    /// Append custom keys to the style of the shared exponent label of the scaled `x` ticks,
+    /// e.g. `at={(1,0)}, anchor=north west`. Multiple styles can be applied to the same axis.
+    XTickScaleLabelStyle(String),
+    /// Append custom keys to the style of the shared exponent label of the scaled `y` ticks.
+    /// Multiple styles can be applied to the same axis.
+    YTickScaleLabelStyle(String),
     /// Control the label of the `x` axis ticks.
     XTickLabel(String),
     /// Control the label of the `y` axis ticks.
@@ -83,8 +145,88 @@ pub enum AxisOption {
     AxisLinesAst(AxisLines),
     /// Control the axis grid lines.
     Grid(Grid),
-    // /// Control the legend style.
-    // LegendStyle(String),
+    /// Apply a named style registered in the document preamble. Unlike the other
+    /// variants, multiple styles can be applied to the same axis.
+    Style(StyleRef),
+    /// Control the cycle list (registered in the document preamble) used to style the
+    /// plots of the axis.
+    CycleListName(CycleListRef),
+    /// Control the name of the axis, used to position other axes or annotations relative to
+    /// it, e.g. with [`Point::Anchor`].
+    Name(String),
+    /// Control the position of the axis [`AxisOption::Anchor`] in the picture.
+    At(Point),
+    /// Control the anchor of the axis placed at the [`AxisOption::At`] position.
+    Anchor(Anchor),
+    /// Shift the axis horizontally.
+    XShift(Length),
+    /// Shift the axis vertically.
+    YShift(Length),
+    /// Control the width of the axis, including its labels unless
+    /// [`AxisOption::ScaleOnlyAxis`] is set.
+    Width(Length),
+    /// Control the height of the axis, including its labels unless
+    /// [`AxisOption::ScaleOnlyAxis`] is set.
+    Height(Length),
+    /// Apply [`AxisOption::Width`] and [`AxisOption::Height`] to the axis area only, ignoring
+    /// its labels, ticks and title: axes of the same size have the same plot area, however
+    /// long their labels are.
+    ScaleOnlyAxis,
+    /// Use the same unit length for all the axes, adjusting the width or the height of the
+    /// axis.
+    AxisEqual,
+    /// Use the same unit length for all the axes, adjusting the axis limits instead of its
+    /// size.
+    AxisEqualImage,
+    /// Control the ratio between the unit lengths of the `x`, `y` (and `z`) axes, e.g.
+    /// `[1.0, 2.0]` to draw a `y` unit twice as long as the `x` one. The axis size is
+    /// adjusted as for [`AxisOption::AxisEqual`].
+    UnitVectorRatio(Vec<f64>),
+    /// Control the space added to the limits of every axis, beyond the data range.
+    EnlargeLimits(Enlarge),
+    /// Control the space added to the limits of the `x` axis, beyond the data range.
+    EnlargeXLimits(Enlarge),
+    /// Control the space added to the limits of the `y` axis, beyond the data range.
+    EnlargeYLimits(Enlarge),
+    /// Control the colormap used to color the plots according to their point meta values.
+    Colormap(Colormap),
+    /// Draw a colorbar next to the axis.
+    Colorbar(Colorbar),
+    /// Control the point meta value mapped to the lowest color of the colormap. Lower
+    /// values are clipped to it.
+    PointMetaMin(f64),
+    /// Control the point meta value mapped to the highest color of the colormap. Higher
+    /// values are clipped to it.
+    PointMetaMax(f64),
+    /// Control the position of the legend.
+    LegendPos(LegendPos),
+    /// Control the number of columns of the legend.
+    LegendColumns(usize),
+    /// Fill the legend columns first, instead of the rows.
+    TransposeLegend,
+    /// Control the font of the legend entries, e.g. `\small`.
+    LegendFont(String),
+    /// Control the color of the legend frame, e.g. `none` to hide it.
+    LegendDraw(String),
+    /// Control the fill color of the legend.
+    LegendFill(String),
+    /// Append custom keys to the legend style. Unlike the other legend variants, multiple
+    /// custom styles can be applied to the same axis.
+    LegendStyle(String),
+}
+
+impl AxisOption {
+    /// Returns the minimum PGFPlots compatibility layer required by the option.
+    ///
+    /// Axis labels are placed next to the tick labels only from [`PgfPlotsCompat::V1_3`]:
+    /// with lower versions they are placed at a fixed distance from the axis, overlapping
+    /// wide tick labels.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        match self {
+            Self::XLabel(_) | Self::YLabel(_) => PgfPlotsCompat::V1_3,
+            _ => PgfPlotsCompat::Default,
+        }
+    }
 }
 
 impl From<&str> for AxisOption {
@@ -110,6 +252,36 @@ impl fmt::Display for AxisOption {
             AxisOption::Title(value) => write!(f, "title={{{value}}}"),
             AxisOption::XTick(value) => write!(f, "xtick={{{value}}}"),
             AxisOption::YTick(value) => write!(f, "ytick={{{value}}}"),
+            AxisOption::ZTick(value) => write!(f, "ztick={{{value}}}"),
+            AxisOption::MinorTickNum(value) => write!(f, "minor tick num={value}"),
+            AxisOption::MinorXTick(value) => write!(f, "minor xtick={{{value}}}"),
+            AxisOption::MinorYTick(value) => write!(f, "minor ytick={{{value}}}"),
+            AxisOption::MinorZTick(value) => write!(f, "minor ztick={{{value}}}"),
+            AxisOption::TickPos(value) => write!(f, "tick pos={value}"),
+            AxisOption::XTickPos(value) => write!(f, "xtick pos={value}"),
+            AxisOption::YTickPos(value) => write!(f, "ytick pos={value}"),
+            AxisOption::TickAlign(value) => write!(f, "tick align={value}"),
+            AxisOption::TickLabelStyle(value) => write!(f, "tick label style={{{value}}}"),
+            AxisOption::XTickLabelStyle(value) => write!(f, "xticklabel style={{{value}}}"),
+            AxisOption::YTickLabelStyle(value) => write!(f, "yticklabel style={{{value}}}"),
+            AxisOption::XTickLabelRotate(value) => {
+                write!(f, "xticklabel style={{rotate={value}}}")
+            }
+            AxisOption::YTickLabelRotate(value) => {
+                write!(f, "yticklabel style={{rotate={value}}}")
+            }
+            AxisOption::NumberFormat(value) => write!(f, "{value}"),
+            AxisOption::XNumberFormat(value) => write!(f, "xticklabel style={{{value}}}"),
+            AxisOption::YNumberFormat(value) => write!(f, "yticklabel style={{{value}}}"),
+            AxisOption::ScaledTicks(value) => write!(f, "scaled ticks={value}"),
+            AxisOption::ScaledXTicks(value) => write!(f, "scaled x ticks={value}"),
+            AxisOption::ScaledYTicks(value) => write!(f, "scaled y ticks={value}"),
+            AxisOption::XTickScaleLabelStyle(value) => {
+                write!(f, "every x tick scale label/.append style={{{value}}}")
+            }
+            AxisOption::YTickScaleLabelStyle(value) => {
+                write!(f, "every y tick scale label/.append style={{{value}}}")
+            }
             AxisOption::XLabel(value) => write!(f, "xlabel={{{value}}}"),
             AxisOption::YLabel(value) => write!(f, "ylabel={{{value}}}"),
             AxisOption::XTickLabel(value) => write!(f, "xticklabel={{{value}}}"),
@@ -126,6 +298,35 @@ impl fmt::Display for AxisOption {
             AxisOption::AxisLines(value) => write!(f, "axis lines={value}"),
             AxisOption::AxisLinesAst(value) => write!(f, "axis lines*={value}"),
             AxisOption::Grid(value) => write!(f, "grid={value}"),
+            AxisOption::LegendPos(value) => write!(f, "{value}"),
+            AxisOption::LegendColumns(value) => write!(f, "legend columns={value}"),
+            AxisOption::TransposeLegend => write!(f, "transpose legend"),
+            AxisOption::LegendFont(value) => write!(f, "legend style={{font={value}}}"),
+            AxisOption::LegendDraw(value) => write!(f, "legend style={{draw={value}}}"),
+            AxisOption::LegendFill(value) => write!(f, "legend style={{fill={value}}}"),
+            AxisOption::LegendStyle(value) => write!(f, "legend style={{{value}}}"),
+            AxisOption::Style(value) => write!(f, "{value}"),
+            AxisOption::CycleListName(value) => write!(f, "cycle list name={value}"),
+            AxisOption::Name(value) => write!(f, "name={value}"),
+            AxisOption::At(value) => write!(f, "at={{{value}}}"),
+            AxisOption::Anchor(value) => write!(f, "anchor={value}"),
+            AxisOption::XShift(value) => write!(f, "xshift={value}"),
+            AxisOption::YShift(value) => write!(f, "yshift={value}"),
+            AxisOption::Width(value) => write!(f, "width={value}"),
+            AxisOption::Height(value) => write!(f, "height={value}"),
+            AxisOption::ScaleOnlyAxis => write!(f, "scale only axis"),
+            AxisOption::AxisEqual => write!(f, "axis equal"),
+            AxisOption::AxisEqualImage => write!(f, "axis equal image"),
+            AxisOption::UnitVectorRatio(value) => {
+                write!(f, "unit vector ratio={{{}}}", value.iter().join(" "))
+            }
+            AxisOption::EnlargeLimits(value) => write!(f, "enlargelimits={value}"),
+            AxisOption::EnlargeXLimits(value) => write!(f, "enlarge x limits={value}"),
+            AxisOption::EnlargeYLimits(value) => write!(f, "enlarge y limits={value}"),
+            AxisOption::Colormap(value) => write!(f, "{value}"),
+            AxisOption::Colorbar(value) => write!(f, "{value}"),
+            AxisOption::PointMetaMin(value) => write!(f, "point meta min={value}"),
+            AxisOption::PointMetaMax(value) => write!(f, "point meta max={value}"),
         }
     }
 }
@@ -160,13 +361,15 @@ impl fmt::Display for AxisOption {
 /// ```
 #[derive(Debug, Default, Clone)]
 pub struct Axis {
+    kind: AxisKind,
     options: Vec<AxisOption>,
     plots: Vec<Plot>,
+    spies: Vec<Spy>,
 }
 
 impl fmt::Display for Axis {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\\begin{{axis}}")?;
+        write!(f, "\\begin{{{}}}", self.kind)?;
         // If there are keys, print one per line. It makes it easier for a
         // human to find individual keys later.
         if !self.options.is_empty() {
@@ -182,7 +385,9 @@ impl fmt::Display for Axis {
             writeln!(f, "{plot}")?;
         }
 
-        write!(f, "\\end{{axis}}")?;
+        self.fmt_spy_coordinates(f, self.spy_name())?;
+
+        write!(f, "\\end{{{}}}", self.kind)?;
 
         Ok(())
     }
@@ -191,8 +396,8 @@ impl fmt::Display for Axis {
 impl From<Plot> for Axis {
     fn from(plot: Plot) -> Self {
         Axis {
-            options: vec![],
             plots: vec![plot],
+            ..Default::default()
         }
     }
 }
@@ -223,6 +428,41 @@ impl Axis {
         Default::default()
     }
 
+    /// Creates a new, empty axis environment with a logarithmic `x` axis
+    /// (`semilogxaxis` environment).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::axis::{Axis, AxisKind};
+    ///
+    /// let axis = Axis::semilog_x();
+    /// assert_eq!(AxisKind::SemiLogX, axis.kind());
+    /// ```
+    pub fn semilog_x() -> Self {
+        Self::with_kind(AxisKind::SemiLogX)
+    }
+
+    /// Creates a new, empty axis environment with a logarithmic `y` axis
+    /// (`semilogyaxis` environment).
+    pub fn semilog_y() -> Self {
+        Self::with_kind(AxisKind::SemiLogY)
+    }
+
+    /// Creates a new, empty axis environment with logarithmic `x` and `y` axes
+    /// (`loglogaxis` environment).
+    pub fn loglog() -> Self {
+        Self::with_kind(AxisKind::LogLog)
+    }
+
+    /// Creates a new, empty axis environment of the given [`AxisKind`].
+    pub fn with_kind(kind: AxisKind) -> Self {
+        Self {
+            kind,
+            ..Default::default()
+        }
+    }
+
     /// Set the `x` axis minimum limit.
     ///
     /// # Examples
@@ -418,6 +658,23 @@ impl Axis {
         self.option(AxisOption::YTick(ticks.into()))
     }
 
+    /// Set the `z` axis ticks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::axis::Axis;
+    ///
+    /// let mut axis = Axis::new()
+    ///     .z_ticks([1.2, 3.0, 4.4]);
+    /// ```
+    pub fn z_ticks<T>(self, ticks: T) -> Self
+    where
+        T: Into<Ticks>,
+    {
+        self.option(AxisOption::ZTick(ticks.into()))
+    }
+
     /// Set the `x` axis tick labels.
     ///
     /// # Examples
@@ -469,6 +726,103 @@ impl Axis {
         self.option(AxisOption::ZTickLabels(tick_labels.into()))
     }
 
+    /// Set the name of the axis, used to position other axes or annotations relative to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::axis::Axis;
+    ///
+    /// let axis = Axis::new().name("main");
+    /// ```
+    pub fn name(self, name: &str) -> Self {
+        self.option(AxisOption::Name(name.to_string()))
+    }
+
+    /// Set the position of the axis `anchor` in the picture.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::{axis::Axis, path::Point, Anchor};
+    ///
+    /// let axis = Axis::new().at(
+    ///     Point::Anchor("main".to_string(), Anchor::NorthEast),
+    ///     Anchor::NorthWest,
+    /// );
+    /// ```
+    pub fn at(self, point: Point, anchor: Anchor) -> Self {
+        self.option(AxisOption::At(point))
+            .option(AxisOption::Anchor(anchor))
+    }
+
+    /// Place the axis to the right of the axis named `name`, with their tops aligned and
+    /// `sep` between their outer borders (i.e. labels included).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::{
+    ///     length::Length,
+    ///     tikzpicture::{axis::Axis, TikzPicture},
+    /// };
+    ///
+    /// let mut picture = TikzPicture::new();
+    /// picture.add_axis(Axis::new().name("left"));
+    /// picture.add_axis(Axis::new().name("right").right_of("left", Length::Pt(10.0)));
+    /// ```
+    pub fn right_of(self, name: &str, sep: Length) -> Self {
+        self.at(
+            Point::Anchor(name.to_string(), Anchor::NorthEast.outer()),
+            Anchor::NorthWest.outer(),
+        )
+        .option(AxisOption::XShift(sep))
+    }
+
+    /// Place the axis below the axis named `name`, with their left borders aligned and `sep`
+    /// between their outer borders (i.e. labels included).
+    pub fn below(self, name: &str, sep: Length) -> Self {
+        self.at(
+            Point::Anchor(name.to_string(), Anchor::SouthWest.outer()),
+            Anchor::NorthWest.outer(),
+        )
+        .option(AxisOption::YShift(-sep))
+    }
+
+    /// Place the axis as an inset in the `corner` of the axis named `name`, `padding` away
+    /// from its borders.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::{
+    ///     length::Length,
+    ///     tikzpicture::{axis::Axis, Anchor, TikzPicture},
+    /// };
+    ///
+    /// let mut picture = TikzPicture::new();
+    /// picture.add_axis(Axis::new().name("main"));
+    /// picture.add_axis(Axis::new().inset("main", Anchor::NorthEast, Length::Pt(5.0)));
+    /// ```
+    pub fn inset(self, name: &str, corner: Anchor, padding: Length) -> Self {
+        let none = Length::Pt(0.0);
+        let (x, y) = match corner {
+            Anchor::NorthEast => (-padding, -padding),
+            Anchor::NorthWest => (padding, -padding),
+            Anchor::SouthEast => (-padding, padding),
+            Anchor::SouthWest => (padding, padding),
+            Anchor::North => (none, -padding),
+            Anchor::South => (none, padding),
+            Anchor::East => (-padding, none),
+            Anchor::West => (padding, none),
+            _ => (none, none),
+        };
+
+        self.at(Point::Anchor(name.to_string(), corner.clone()), corner)
+            .option(AxisOption::XShift(x))
+            .option(AxisOption::YShift(y))
+    }
+
     /// Add a option to control the appearance of the axis. This will overwrite
     /// any previous mutually exclusive option.
     ///
@@ -482,7 +836,14 @@ impl Axis {
     /// ```
     pub fn option(mut self, option: AxisOption) -> Self {
         match option {
-            AxisOption::Custom(_) => (),
+            AxisOption::Custom(_)
+            | AxisOption::Style(_)
+            | AxisOption::LegendStyle(_)
+            | AxisOption::TickLabelStyle(_)
+            | AxisOption::XTickLabelStyle(_)
+            | AxisOption::YTickLabelStyle(_)
+            | AxisOption::XTickScaleLabelStyle(_)
+            | AxisOption::YTickScaleLabelStyle(_) => (),
             _ => {
                 if let Some(index) = self
                     .options
@@ -503,6 +864,27 @@ impl Axis {
         self
     }
 
+    /// Add a [`Spy`] magnifying a region of the [`Axis`]. Unless already named (see
+    /// [`Axis::name`]), the axis is named after its position in the
+    /// [`super::TikzPicture`], e.g. `axis 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::{
+    ///     axis::Axis,
+    ///     spy::{Spy, SpyScope},
+    ///     TikzPicture, TikzPictureOption,
+    /// };
+    ///
+    /// let axis = Axis::new().name("main").spy(Spy::new((1.0, 0.5), (4.0, 2.0)));
+    /// let picture = TikzPicture::from(axis).option(TikzPictureOption::SpyUsing(SpyScope::new(4.0)));
+    /// ```
+    pub fn spy(mut self, spy: Spy) -> Self {
+        self.add_spy(spy);
+        self
+    }
+
     /// Set the `x` axis minimum limit.
     ///
     /// # Examples
@@ -698,6 +1080,23 @@ impl Axis {
         self.add_option(AxisOption::YTick(ticks.into()));
     }
 
+    /// Set the `z` axis ticks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_z_ticks([1.2, 3.0, 4.4]);
+    /// ```
+    pub fn set_z_ticks<T>(&mut self, ticks: T)
+    where
+        T: Into<Ticks>,
+    {
+        self.add_option(AxisOption::ZTick(ticks.into()));
+    }
+
     /// Set the `x` axis tick labels.
     ///
     /// # Examples
@@ -749,6 +1148,17 @@ impl Axis {
         self.add_option(AxisOption::ZTickLabels(tick_labels.into()));
     }
 
+    /// Set the name of the axis, used to position other axes or annotations relative to it.
+    pub fn set_name(&mut self, name: &str) {
+        self.add_option(AxisOption::Name(name.to_string()));
+    }
+
+    /// Set the position of the axis `anchor` in the picture.
+    pub fn set_at(&mut self, point: Point, anchor: Anchor) {
+        self.add_option(AxisOption::At(point));
+        self.add_option(AxisOption::Anchor(anchor));
+    }
+
     /// Add a option to control the appearance of the axis. This will overwrite
     /// any previous mutually exclusive option.
     ///
@@ -762,7 +1172,14 @@ impl Axis {
     /// ```
     pub fn add_option(&mut self, option: AxisOption) {
         match option {
-            AxisOption::Custom(_) => (),
+            AxisOption::Custom(_)
+            | AxisOption::Style(_)
+            | AxisOption::LegendStyle(_)
+            | AxisOption::TickLabelStyle(_)
+            | AxisOption::XTickLabelStyle(_)
+            | AxisOption::YTickLabelStyle(_)
+            | AxisOption::XTickScaleLabelStyle(_)
+            | AxisOption::YTickScaleLabelStyle(_) => (),
             _ => {
                 if let Some(index) = self
                     .options
@@ -781,13 +1198,252 @@ impl Axis {
         self.plots.push(plot);
     }
 
-    /// Returns a vector of [`PgfPlotsLib`]s required by the contained plots.
+    /// Add a [`Spy`] magnifying a region of the [`Axis`]. Unless already named (see
+    /// [`Axis::set_name`]), the axis is named after its position in the
+    /// [`super::TikzPicture`], e.g. `axis 1`.
+    pub fn add_spy(&mut self, spy: Spy) {
+        self.spies.push(spy);
+    }
+
+    /// Returns the spies of the [`Axis`].
+    pub(crate) fn spies(&self) -> &[Spy] {
+        &self.spies
+    }
+
+    /// Returns the name of the [`Axis`], if set.
+    pub(crate) fn name_ref(&self) -> Option<&str> {
+        self.options.iter().find_map(|option| match option {
+            AxisOption::Name(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns the name prefixing the coordinates of the spies of the [`Axis`].
+    pub(crate) fn spy_name(&self) -> &str {
+        self.name_ref().unwrap_or("axis")
+    }
+
+    /// Set the name of the [`Axis`] to `name` if it has spies but no name yet, so that the
+    /// coordinates of its spies do not clash with those of other axes.
+    pub(crate) fn name_spied(&mut self, name: &str) {
+        if !self.spies.is_empty() && self.name_ref().is_none() {
+            self.set_name(name);
+        }
+    }
+
+    /// Writes the coordinates of the spies of the [`Axis`] named after `prefix`, inside the
+    /// axis: the `\spy` commands are written after the axis by the [`super::TikzPicture`].
+    pub(crate) fn fmt_spy_coordinates(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        prefix: &str,
+    ) -> fmt::Result {
+        for (n, spy) in self.spies.iter().enumerate() {
+            for path in spy.coordinates(prefix, n + 1) {
+                writeln!(f, "\t{path}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of the [`Axis`] restricted to the region of the [`ZoomInset`], named
+    /// `name` and placed in the inset corner of the [`Axis`] named `parent`.
+    ///
+    /// The legend and the colorbar are only drawn by the [`Axis`]: the copy has neither.
+    pub(crate) fn zoomed(&self, inset: &ZoomInset, parent: &str, name: &str) -> Self {
+        let options = self
+            .options
+            .iter()
+            .filter(|option| {
+                !matches!(
+                    option,
+                    AxisOption::Name(_)
+                        | AxisOption::At(_)
+                        | AxisOption::Anchor(_)
+                        | AxisOption::XShift(_)
+                        | AxisOption::YShift(_)
+                        | AxisOption::Title(_)
+                        | AxisOption::XLabel(_)
+                        | AxisOption::YLabel(_)
+                        | AxisOption::LegendPos(_)
+                        | AxisOption::LegendColumns(_)
+                        | AxisOption::TransposeLegend
+                        | AxisOption::LegendFont(_)
+                        | AxisOption::LegendDraw(_)
+                        | AxisOption::LegendFill(_)
+                        | AxisOption::LegendStyle(_)
+                        | AxisOption::Colorbar(_)
+                )
+            })
+            .cloned()
+            .collect();
+        let plots = self
+            .plots
+            .iter()
+            .filter(|plot| !matches!(plot, Plot::LegendImage(_)))
+            .cloned()
+            .map(|mut plot| {
+                plot.take_legend();
+                plot
+            })
+            .collect();
+
+        Self {
+            kind: self.kind,
+            options,
+            plots,
+            spies: vec![],
+        }
+        .name(name)
+        .inset(parent, inset.corner.clone(), inset.padding)
+        .option(AxisOption::XMin(inset.x.0))
+        .option(AxisOption::XMax(inset.x.1))
+        .option(AxisOption::YMin(inset.y.0))
+        .option(AxisOption::YMax(inset.y.1))
+        .option(AxisOption::Width(inset.size))
+        .option(AxisOption::Height(inset.size))
+        .option(AxisOption::ScaleOnlyAxis)
+        .option(AxisOption::Custom(
+            "axis background/.style={fill=white}".to_string(),
+        ))
+    }
+
+    /// Returns the options of the [`Axis`].
+    pub(crate) fn options(&self) -> &[AxisOption] {
+        &self.options
+    }
+
+    /// Returns the plots of the [`Axis`].
+    pub(crate) fn plots(&self) -> &[Plot] {
+        &self.plots
+    }
+
+    /// Returns the plots of the [`Axis`], mutably.
+    pub(crate) fn plots_mut(&mut self) -> &mut [Plot] {
+        &mut self.plots
+    }
+
+    /// Returns the [`AxisKind`] of the [`Axis`], i.e. the environment it renders as.
+    pub fn kind(&self) -> AxisKind {
+        self.kind
+    }
+
+    /// Set the [`AxisKind`] of the [`Axis`], i.e. the environment it renders as.
+    pub fn set_kind(&mut self, kind: AxisKind) {
+        self.kind = kind;
+    }
+
+    /// Returns whether the `x` and `y` axes are logarithmic, either because of the
+    /// [`AxisKind`] or of an [`AxisOption::XMode`] or [`AxisOption::YMode`] option.
+    pub(crate) fn log_dimensions(&self) -> (bool, bool) {
+        let log_x = self.kind.log_x()
+            || self
+                .options
+                .iter()
+                .any(|option| matches!(option, AxisOption::XMode(Scale::Log)));
+        let log_y = self.kind.log_y()
+            || self
+                .options
+                .iter()
+                .any(|option| matches!(option, AxisOption::YMode(Scale::Log)));
+        (log_x, log_y)
+    }
+
+    /// Checks that the coordinates of the contained [`Plot2D`]s are strictly positive on the
+    /// logarithmic dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoordinateError::NonPositiveLog`] for the first offending coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::axis::{plot::bidimensional::Plot2D, Axis};
+    ///
+    /// let axis = Axis::loglog().plot(Plot2D::new().coordinates([(0.0, 1.0).into()]).into());
+    /// assert!(axis.check().is_err());
+    /// ```
+    pub fn check(&self) -> Result<(), CoordinateError> {
+        let (log_x, log_y) = self.log_dimensions();
+        self.check_log(log_x, log_y)
+    }
+
+    /// Checks that the coordinates of the contained plots are strictly positive on the given
+    /// logarithmic dimensions.
+    pub(crate) fn check_log(&self, log_x: bool, log_y: bool) -> Result<(), CoordinateError> {
+        self.plots
+            .iter()
+            .try_for_each(|plot| plot.check_log(log_x, log_y))
+    }
+
+    /// Returns a vector of [`PgfPlotsLib`]s required by the contained plots, each appearing once.
     pub fn required_libs(&self) -> Vec<PgfPlotsLib> {
         self.plots
             .iter()
             .filter_map(|plot| plot.required_lib())
+            .unique()
             .collect()
     }
+
+    /// Returns the minimum PGFPlots compatibility layer required by the axis options and the
+    /// contained plots.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        self.options
+            .iter()
+            .map(AxisOption::required_pgfcompat)
+            .chain(self.plots.iter().map(Plot::required_pgfcompat))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns a vector of [`TikzLib`]s required by the contained plots and spies, each
+    /// appearing once.
+    pub fn required_tikzlibs(&self) -> Vec<TikzLib> {
+        self.plots
+            .iter()
+            .flat_map(|plot| plot.required_tikzlibs())
+            .chain((!self.spies.is_empty()).then_some(TikzLib::Spy))
+            .unique()
+            .collect()
+    }
+}
+
+/// PGFPlots environment an [`Axis`] renders as.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AxisKind {
+    /// Linear `x` and `y` axes (`axis` environment).
+    #[default]
+    Linear,
+    /// Logarithmic `x` axis (`semilogxaxis` environment).
+    SemiLogX,
+    /// Logarithmic `y` axis (`semilogyaxis` environment).
+    SemiLogY,
+    /// Logarithmic `x` and `y` axes (`loglogaxis` environment).
+    LogLog,
+}
+
+impl fmt::Display for AxisKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Linear => "axis",
+            Self::SemiLogX => "semilogxaxis",
+            Self::SemiLogY => "semilogyaxis",
+            Self::LogLog => "loglogaxis",
+        })
+    }
+}
+
+impl AxisKind {
+    /// Returns whether the `x` axis is logarithmic.
+    pub fn log_x(&self) -> bool {
+        matches!(self, Self::SemiLogX | Self::LogLog)
+    }
+
+    /// Returns whether the `y` axis is logarithmic.
+    pub fn log_y(&self) -> bool {
+        matches!(self, Self::SemiLogY | Self::LogLog)
+    }
 }
 
 /// Control the scaling of an axis.
@@ -899,6 +1555,27 @@ impl fmt::Display for Grid {
     }
 }
 
+/// Space added to the limits of an axis, beyond the range of its data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Enlarge {
+    /// Limits fit the data range exactly.
+    None,
+    /// Limits enlarged on both sides by the given fraction of the data range, e.g. `0.1`.
+    Relative(f64),
+    /// Limits enlarged on both sides by the given length.
+    Absolute(Length),
+}
+
+impl fmt::Display for Enlarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Enlarge::None => write!(f, "false"),
+            Enlarge::Relative(value) => write!(f, "{{{value}}}"),
+            Enlarge::Absolute(value) => write!(f, "{{abs={value}}}"),
+        }
+    }
+}
+
 /// Control the axis ticks by assigning a list of positions where ticks shall be placed.
 #[derive(Debug, Clone)]
 pub struct Ticks(Vec<f64>);
@@ -941,9 +1618,116 @@ impl fmt::Display for TickLabels {
 mod test {
     use super::*;
 
+    #[test]
+    fn placement() {
+        let axis = Axis::new()
+            .name("inset")
+            .inset("main", Anchor::SouthWest, Length::Pt(4.0));
+        assert_eq!(
+            r#"\begin{axis}[
+	name=inset,
+	at={(main.south west)},
+	anchor=south west,
+	xshift=4pt,
+	yshift=4pt,
+]
+\end{axis}"#,
+            axis.to_string()
+        );
+
+        let axis = Axis::new().below("main", Length::Pt(10.0));
+        assert_eq!(
+            r#"\begin{axis}[
+	at={(main.outer south west)},
+	anchor=outer north west,
+	yshift=-10pt,
+]
+\end{axis}"#,
+            axis.to_string()
+        );
+    }
+
+    #[test]
+    fn sizing() {
+        let axis = Axis::new()
+            .option(AxisOption::Width(Length::Cm(6.0)))
+            .option(AxisOption::Height(Length::LineWidth(0.3)))
+            .option(AxisOption::ScaleOnlyAxis)
+            .option(AxisOption::UnitVectorRatio(vec![1.0, 2.0]))
+            .option(AxisOption::EnlargeLimits(Enlarge::None))
+            .option(AxisOption::EnlargeXLimits(Enlarge::Relative(0.05)))
+            .option(AxisOption::EnlargeYLimits(Enlarge::Absolute(Length::Mm(
+                2.0,
+            ))))
+            .option(AxisOption::Width(Length::Cm(8.0)));
+        assert_eq!(
+            r#"\begin{axis}[
+	height=0.3\linewidth,
+	scale only axis,
+	unit vector ratio={1 2},
+	enlargelimits=false,
+	enlarge x limits={0.05},
+	enlarge y limits={abs=2mm},
+	width=8cm,
+]
+\end{axis}"#,
+            axis.to_string()
+        );
+    }
+
+    #[test]
+    fn log_axes() {
+        let plot = Plot2D::new().coordinates([(1.0, -1.0).into(), (10.0, 1.0).into()]);
+        let axis = Axis::semilog_x().plot(plot.clone().into());
+        assert_eq!(Ok(()), axis.check());
+        assert_eq!(
+            r#"\begin{semilogxaxis}
+	\addplot[] coordinates {
+		(1,-1)
+		(10,1)
+	};
+\end{semilogxaxis}"#,
+            axis.to_string()
+        );
+
+        let axis = Axis::from(plot).option(AxisOption::YMode(Scale::Log));
+        assert_eq!(
+            Err(CoordinateError::NonPositiveLog {
+                dimension: 'y',
+                coordinate: (1.0, -1.0)
+            }),
+            axis.check()
+        );
+    }
+
     #[test]
     fn ticks() {
         let ticks = Ticks::from([1.0, 2.2, 3.3, 4.0].as_slice());
         assert_eq!(r#"1, 2.2, 3.3, 4"#, ticks.to_string());
+
+        let axis = Axis::new()
+            .z_ticks([0.0, 1.0])
+            .option(AxisOption::MinorTickNum(4))
+            .option(AxisOption::TickPos(TickPos::Lower))
+            .option(AxisOption::XTickLabelRotate(45.0))
+            .option(AxisOption::XTickLabelStyle("anchor=north east".to_string()))
+            .option(AxisOption::ScaledYTicks(ScaledTicks::Base10(3)))
+            .option(AxisOption::YTickScaleLabelStyle("at={(0,1)}".to_string()))
+            .option(AxisOption::YNumberFormat(NumberFormat::new().precision(1)))
+            .option(AxisOption::MinorTickNum(1));
+        assert_eq!(
+            r#"\begin{axis}[
+	ztick={0, 1},
+	tick pos=left,
+	xticklabel style={rotate=45},
+	xticklabel style={anchor=north east},
+	scaled y ticks=base 10:3,
+	every y tick scale label/.append style={at={(0,1)}},
+	yticklabel style={/pgf/number format/precision=1},
+	minor tick num=1,
+]
+\end{axis}"#,
+            axis.to_string()
+        );
     }
 }
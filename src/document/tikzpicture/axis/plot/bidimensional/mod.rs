@@ -1,5 +1,13 @@
 pub mod coordinate;
 
+use crate::{
+    document::{
+        length::{BarLength, Length},
+        preamble::{PgfPlotsCompat, StyleRef},
+        tikzpicture::axis::legend,
+    },
+    error::CoordinateError,
+};
 use coordinate::Coordinate2D;
 use std::fmt;
 
@@ -33,6 +41,13 @@ pub enum PlotOption {
     /// Note that error bars won't be drawn unless [`PlotOption::YError`] is also
     /// set.
     YErrorDirection(ErrorDirection),
+    /// Control the size of the markers.
+    MarkSize(Length),
+    /// Control the width of the plot lines.
+    LineWidth(Length),
+    /// Apply a named style registered in the document preamble. Unlike the other
+    /// variants, multiple styles can be applied to the same plot.
+    Style(StyleRef),
 }
 
 impl fmt::Display for PlotOption {
@@ -44,6 +59,19 @@ impl fmt::Display for PlotOption {
             PlotOption::XErrorDirection(value) => write!(f, "error bars/x dir={value}"),
             PlotOption::YError(value) => write!(f, "error bars/y {value}"),
             PlotOption::YErrorDirection(value) => write!(f, "error bars/y dir={value}"),
+            PlotOption::MarkSize(value) => write!(f, "mark size={value}"),
+            PlotOption::LineWidth(value) => write!(f, "line width={value}"),
+            PlotOption::Style(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl PlotOption {
+    /// Returns the minimum PGFPlots compatibility layer required by the option.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        match self {
+            PlotOption::Type2D(value) => value.required_pgfcompat(),
+            _ => PgfPlotsCompat::Default,
         }
     }
 }
@@ -85,6 +113,8 @@ impl From<&str> for PlotOption {
 pub struct Plot2D {
     options: Vec<PlotOption>,
     coordinates: Vec<Coordinate2D>,
+    /// Legend entry, written as `\addlegendentry{...}` after the plot.
+    legend: Option<String>,
 }
 
 impl fmt::Display for Plot2D {
@@ -106,6 +136,7 @@ impl fmt::Display for Plot2D {
         }
 
         write!(f, "\t}};")?;
+        legend::fmt_entry(f, self.legend.as_deref())?;
 
         Ok(())
     }
@@ -117,8 +148,8 @@ where
 {
     fn from(coordinates: C) -> Self {
         Self {
-            options: Default::default(),
             coordinates: coordinates.into(),
+            ..Default::default()
         }
     }
 }
@@ -150,7 +181,7 @@ impl Plot2D {
     /// ```
     pub fn option(mut self, option: PlotOption) -> Self {
         match option {
-            PlotOption::Custom(_) => (),
+            PlotOption::Custom(_) | PlotOption::Style(_) => (),
             _ => {
                 if let Some(index) = self
                     .options
@@ -178,7 +209,7 @@ impl Plot2D {
     /// ```
     pub fn add_option(&mut self, key: PlotOption) {
         match key {
-            PlotOption::Custom(_) => (),
+            PlotOption::Custom(_) | PlotOption::Style(_) => (),
             _ => {
                 if let Some(index) = self
                     .options
@@ -193,9 +224,9 @@ impl Plot2D {
     }
 
     /// Sets plot coordiantes (chaining version).
-    pub fn coordinates<C>(mut self, coordinates: C) -> Self 
+    pub fn coordinates<C>(mut self, coordinates: C) -> Self
     where
-        C: Into<Vec<Coordinate2D>>
+        C: Into<Vec<Coordinate2D>>,
     {
         self.coordinates = coordinates.into();
         self
@@ -204,27 +235,74 @@ impl Plot2D {
     /// Pushes plot coordinate (chaining version).
     pub fn coordinate<C>(mut self, coordinate: C) -> Self
     where
-        C: Into<Coordinate2D>
+        C: Into<Coordinate2D>,
     {
         self.coordinates.push(coordinate.into());
         self
     }
 
+    /// Set the legend entry of the plot (chaining version).
+    pub fn legend(mut self, entry: &str) -> Self {
+        self.set_legend(entry);
+        self
+    }
+
+    /// Set the legend entry of the plot.
+    pub fn set_legend(&mut self, entry: &str) {
+        self.legend = Some(entry.to_string());
+    }
+
+    /// Removes and returns the legend entry of the plot.
+    pub(crate) fn take_legend(&mut self) -> Option<String> {
+        self.legend.take()
+    }
+
+    /// Returns whether the plot has a legend entry.
+    pub(crate) fn has_legend(&self) -> bool {
+        self.legend.is_some()
+    }
+
     /// Sets plot coordinates.
     pub fn set_coordinates<C>(&mut self, coordinates: C)
     where
-        C: Into<Vec<Coordinate2D>>
+        C: Into<Vec<Coordinate2D>>,
     {
         self.coordinates = coordinates.into();
     }
 
+    /// Returns the minimum PGFPlots compatibility layer required by the plot options.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        self.options
+            .iter()
+            .map(PlotOption::required_pgfcompat)
+            .max()
+            .unwrap_or_default()
+    }
+
     /// Pushes plot coordinate.
     pub fn add_coordinate<C>(&mut self, coordinate: C)
     where
-        C: Into<Coordinate2D>
+        C: Into<Coordinate2D>,
     {
         self.coordinates.push(coordinate.into());
     }
+
+    /// Checks that the coordinates are strictly positive on the logarithmic dimensions.
+    pub(crate) fn check_log(&self, log_x: bool, log_y: bool) -> Result<(), CoordinateError> {
+        for coordinate in self.coordinates.iter() {
+            for (dimension, log, value) in [('x', log_x, coordinate.x), ('y', log_y, coordinate.y)]
+            {
+                if log && (value.is_nan() || value <= 0.0) {
+                    return Err(CoordinateError::NonPositiveLog {
+                        dimension,
+                        coordinate: (coordinate.x, coordinate.y),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Control the type of two dimensional plots.
@@ -256,30 +334,32 @@ pub enum Type2D {
     /// `bar_width` field controls the width of the horizontal bars, and
     /// `bar_shift` controls the vertical shift. Unless you are plotting
     /// multiple bars in the same [`Axis`], you most likely want
-    /// `bar_shift: 0.0`.
+    /// `bar_shift: BarLength::Axis(0.0)`.
     ///
     /// # Note
     ///
-    /// By default, `bar_width` and `bar_shift` are assumed to be in `pt` units.
-    /// If you want them to be interpreted as axis units (this is most likely
-    /// what you want), you need to add the plot to an [`Axis`], add the
-    /// [`Axis`] to a [`Picture`], and set `compat=1.7` or higher on the
-    /// [`Picture`].
-    XBar { bar_width: f64, bar_shift: f64 },
+    /// [`BarLength::Axis`] values are interpreted as axis units, which requires
+    /// `compat=1.7` or higher: the compatibility layer of the
+    /// [`crate::document::Document`] is raised accordingly, unless explicitly set.
+    XBar {
+        bar_width: BarLength,
+        bar_shift: BarLength,
+    },
     /// Draw vertical bars between the *x = 0* line and each coordinate. The
     /// `bar_width` field controls the width of the vertical bars, and
     /// `bar_shift` controls the horizontal shift. Unless you are plotting
     /// multiple bars in the same [`Axis`], you most likely want
-    /// `bar_shift: 0.0`.
+    /// `bar_shift: BarLength::Axis(0.0)`.
     ///
     /// # Note
     ///
-    /// By default, `bar_width` and `bar_shift` are assumed to be in `pt` units.
-    /// If you want them to be interpreted as axis units (this is most likely
-    /// what you want), you need to add the plot to an [`Axis`], add the
-    /// [`Axis`] to a [`Picture`], and set `compat=1.7` or higher on the
-    /// [`Picture`].
-    YBar { bar_width: f64, bar_shift: f64 },
+    /// [`BarLength::Axis`] values are interpreted as axis units, which requires
+    /// `compat=1.7` or higher: the compatibility layer of the
+    /// [`crate::document::Document`] is raised accordingly, unless explicitly set.
+    YBar {
+        bar_width: BarLength,
+        bar_shift: BarLength,
+    },
     /// Similar to [`Type2D::XBar`] except that it draws a single horizontal
     /// lines instead of rectangles.
     XComb,
@@ -290,6 +370,25 @@ pub enum Type2D {
     OnlyMarks,
 }
 
+impl Type2D {
+    /// Returns the minimum PGFPlots compatibility layer required by the plot type.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        match self {
+            Type2D::XBar {
+                bar_width,
+                bar_shift,
+            }
+            | Type2D::YBar {
+                bar_width,
+                bar_shift,
+            } => bar_width
+                .required_pgfcompat()
+                .max(bar_shift.required_pgfcompat()),
+            _ => PgfPlotsCompat::Default,
+        }
+    }
+}
+
 impl fmt::Display for Type2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -1,6 +1,13 @@
 pub mod bidimensional;
 
-use crate::{document::preamble::PgfPlotsLib, libs::statistics::histogram::Histogram};
+use crate::{
+    document::{
+        preamble::{PgfPlotsCompat, PgfPlotsLib, TikzLib},
+        tikzpicture::{axis::legend::LegendImage, path::Path},
+    },
+    error::CoordinateError,
+    libs::statistics::histogram::Histogram,
+};
 use bidimensional::Plot2D;
 use std::fmt;
 
@@ -23,9 +30,14 @@ use std::fmt;
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum Plot {
+    /// Verbatim Ti*k*Z drawing command (`\draw {string};`): prefer [`Plot::Path`].
     Draw(String),
+    /// Typed Ti*k*Z path, e.g. to annotate the plots of the axis.
+    Path(Path),
     Plot2D(Plot2D),
     Histogram(Histogram),
+    /// Legend entry that does not correspond to a plot.
+    LegendImage(LegendImage),
 }
 
 impl From<Histogram> for Plot {
@@ -34,6 +46,18 @@ impl From<Histogram> for Plot {
     }
 }
 
+impl From<Path> for Plot {
+    fn from(path: Path) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<LegendImage> for Plot {
+    fn from(image: LegendImage) -> Self {
+        Self::LegendImage(image)
+    }
+}
+
 impl From<Plot2D> for Plot {
     fn from(plot: Plot2D) -> Self {
         Self::Plot2D(plot)
@@ -44,8 +68,10 @@ impl fmt::Display for Plot {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Plot::Draw(draw) => write!(f, "\\draw {draw};"),
+            Plot::Path(path) => write!(f, "{path}"),
             Plot::Plot2D(plot) => write!(f, "{plot}"),
             Plot::Histogram(plot) => write!(f, "{plot}"),
+            Plot::LegendImage(image) => write!(f, "{image}"),
         }
     }
 }
@@ -54,11 +80,57 @@ impl Plot {
     /// Returns the required PGFPlots library for the [`Plot`].
     pub fn required_lib(&self) -> Option<PgfPlotsLib> {
         match self {
-            Self::Draw(_) => None,
+            Self::Draw(_) | Self::Path(_) | Self::LegendImage(_) => None,
             Self::Plot2D(_) => None,
             Self::Histogram(_) => Some(PgfPlotsLib::Statistics),
         }
     }
+
+    /// Returns the minimum PGFPlots compatibility layer required by the [`Plot`].
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        match self {
+            Self::Draw(_) | Self::Path(_) | Self::LegendImage(_) => PgfPlotsCompat::Default,
+            Self::Plot2D(plot) => plot.required_pgfcompat(),
+            Self::Histogram(plot) => plot.required_pgfcompat(),
+        }
+    }
+
+    /// Returns the Ti*k*Z libraries required by the [`Plot`].
+    pub fn required_tikzlibs(&self) -> Vec<TikzLib> {
+        match self {
+            Self::Draw(_) => vec![],
+            Self::Path(path) => path.required_tikzlibs(),
+            Self::Plot2D(_) => vec![],
+            Self::Histogram(_) | Self::LegendImage(_) => vec![],
+        }
+    }
+
+    /// Removes and returns the legend entry of the [`Plot`], if any.
+    pub(crate) fn take_legend(&mut self) -> Option<String> {
+        match self {
+            Self::Plot2D(plot) => plot.take_legend(),
+            Self::Histogram(plot) => plot.take_legend(),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the [`Plot`] has a legend entry.
+    pub(crate) fn has_legend(&self) -> bool {
+        match self {
+            Self::Plot2D(plot) => plot.has_legend(),
+            Self::Histogram(plot) => plot.has_legend(),
+            _ => false,
+        }
+    }
+
+    /// Checks that the coordinates of the [`Plot`] are strictly positive on the logarithmic
+    /// dimensions.
+    pub(crate) fn check_log(&self, log_x: bool, log_y: bool) -> Result<(), CoordinateError> {
+        match self {
+            Self::Plot2D(plot) => plot.check_log(log_x, log_y),
+            _ => Ok(()),
+        }
+    }
 }
 
 #[cfg(test)]
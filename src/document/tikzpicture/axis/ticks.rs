@@ -0,0 +1,205 @@
+use std::fmt;
+
+/// Control on which side of the axis the ticks are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TickPos {
+    /// Ticks drawn on the lower side only, i.e. bottom for the `x` axis and left for the `y`
+    /// axis.
+    Lower,
+    /// Ticks drawn on the upper side only, i.e. top for the `x` axis and right for the `y`
+    /// axis.
+    Upper,
+    /// Ticks drawn on both sides.
+    Both,
+}
+
+impl fmt::Display for TickPos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `left` and `right` are understood by every PGFPlots version, unlike their
+        // `lower` and `upper` aliases.
+        match self {
+            TickPos::Lower => write!(f, "left"),
+            TickPos::Upper => write!(f, "right"),
+            TickPos::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// Control the alignment of the ticks with the axis line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TickAlign {
+    /// Ticks drawn inside the axis.
+    Inside,
+    /// Ticks drawn outside the axis.
+    Outside,
+    /// Ticks centered on the axis line.
+    Center,
+}
+
+impl fmt::Display for TickAlign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TickAlign::Inside => write!(f, "inside"),
+            TickAlign::Outside => write!(f, "outside"),
+            TickAlign::Center => write!(f, "center"),
+        }
+    }
+}
+
+/// Notation of the numbers formatted with a [`NumberFormat`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Notation {
+    /// Fixed point notation, e.g. `12345.67`.
+    Fixed,
+    /// Scientific notation, e.g. `1.23 · 10^4`.
+    Sci,
+    /// Fixed point notation for numbers in a reasonable range, scientific notation otherwise
+    /// (PGF default).
+    Std,
+}
+
+impl fmt::Display for Notation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Notation::Fixed => write!(f, "fixed"),
+            Notation::Sci => write!(f, "sci"),
+            Notation::Std => write!(f, "std"),
+        }
+    }
+}
+
+/// Format of the tick labels numbers, written as `/pgf/number format` keys:
+///
+/// ```text
+/// /pgf/number format/fixed, /pgf/number format/precision=2, /pgf/number format/zerofill
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::tikzpicture::axis::{
+///     ticks::{NumberFormat, Notation},
+///     Axis, AxisOption,
+/// };
+///
+/// // Years, e.g. `2024` instead of `2,024`.
+/// let years = NumberFormat::new().thousands_sep("");
+/// // Two decimal places, e.g. `0.50` instead of `0.5`.
+/// let values = NumberFormat::new()
+///     .notation(Notation::Fixed)
+///     .precision(2)
+///     .zerofill();
+/// let axis = Axis::new()
+///     .option(AxisOption::XNumberFormat(years))
+///     .option(AxisOption::YNumberFormat(values));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NumberFormat {
+    notation: Option<Notation>,
+    precision: Option<usize>,
+    zerofill: bool,
+    thousands_sep: Option<String>,
+}
+
+impl fmt::Display for NumberFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut keys = vec![];
+        if let Some(notation) = self.notation {
+            keys.push(notation.to_string());
+        }
+        if let Some(precision) = self.precision {
+            keys.push(format!("precision={precision}"));
+        }
+        if self.zerofill {
+            keys.push("zerofill".to_string());
+        }
+        if let Some(sep) = &self.thousands_sep {
+            keys.push(format!("1000 sep={{{sep}}}"));
+        }
+
+        // Keys are written with their full path rather than after a `.cd`, which would
+        // change the path of the following axis options.
+        let keys: Vec<_> = keys
+            .iter()
+            .map(|key| format!("/pgf/number format/{key}"))
+            .collect();
+        write!(f, "{}", keys.join(", "))
+    }
+}
+
+impl NumberFormat {
+    /// Creates a new number format, equivalent to the PGF default.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the [`Notation`] of the numbers.
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = Some(notation);
+        self
+    }
+
+    /// Set the number of digits after the decimal point (`2` by default).
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Fill the numbers with trailing zeros up to the precision, e.g. `0.50` instead of
+    /// `0.5`.
+    pub fn zerofill(mut self) -> Self {
+        self.zerofill = true;
+        self
+    }
+
+    /// Set the separator inserted every three digits of the integer part, e.g. `\,` for a
+    /// thin space or an empty string to disable it (`,` by default).
+    pub fn thousands_sep(mut self, sep: &str) -> Self {
+        self.thousands_sep = Some(sep.to_string());
+        self
+    }
+}
+
+/// Control the scaling of the ticks: large or small tick values are divided by a common
+/// factor, shown once in a shared exponent label (e.g. `· 10^4`) near the axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScaledTicks {
+    /// Ticks scaled by a power of 10 chosen automatically (PGFPlots default).
+    Auto,
+    /// Ticks not scaled, and no shared exponent label.
+    None,
+    /// Ticks multiplied by `10^e`, e.g. `3` for values of the order of `10^-3`.
+    Base10(i32),
+    /// Ticks divided by the given number.
+    Real(f64),
+}
+
+impl fmt::Display for ScaledTicks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaledTicks::Auto => write!(f, "true"),
+            ScaledTicks::None => write!(f, "false"),
+            ScaledTicks::Base10(exponent) => write!(f, "base 10:{exponent}"),
+            ScaledTicks::Real(value) => write!(f, "real:{value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn number_format() {
+        let format = NumberFormat::new()
+            .notation(Notation::Fixed)
+            .precision(2)
+            .zerofill()
+            .thousands_sep("\\,");
+        assert_eq!(
+            r#"/pgf/number format/fixed, /pgf/number format/precision=2, /pgf/number format/zerofill, /pgf/number format/1000 sep={\,}"#,
+            format.to_string()
+        );
+        assert_eq!("base 10:-3", ScaledTicks::Base10(-3).to_string());
+    }
+}
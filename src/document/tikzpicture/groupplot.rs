@@ -0,0 +1,443 @@
+use super::axis::{Axis, AxisOption, Scale};
+use crate::{
+    document::{
+        length::Length,
+        preamble::{PgfPlotsCompat, PgfPlotsLib, TikzLib},
+    },
+    error::{CoordinateError, LayoutError},
+};
+use itertools::Itertools;
+use std::fmt;
+
+/// Edge of a [`GroupPlot`] grid, selecting the panels on which labels are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    /// Labels drawn on all the panels.
+    All,
+    /// Labels drawn on the bottom row only.
+    Bottom,
+    /// Labels drawn on the top row only.
+    Top,
+    /// Labels drawn on the left column only.
+    Left,
+    /// Labels drawn on the right column only.
+    Right,
+}
+
+impl fmt::Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::All => "all",
+            Self::Bottom => "edge bottom",
+            Self::Top => "edge top",
+            Self::Left => "edge left",
+            Self::Right => "edge right",
+        })
+    }
+}
+
+/// PGFPlots options passed to the `group style` of a [`GroupPlot`].
+///
+/// The [`GroupStyleOption::Custom`] variant is provided to add unimplemented keys and will be
+/// written verbatim in the group style.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GroupStyleOption {
+    /// Custom key-value pairs that have not been implemented.
+    Custom(String),
+    /// Control the grid size.
+    GroupSize { columns: usize, rows: usize },
+    /// Control the horizontal separation between panels.
+    HorizontalSep(Length),
+    /// Control the vertical separation between panels.
+    VerticalSep(Length),
+    /// Control the panels on which the `x` axis labels are drawn.
+    XLabelsAt(Edge),
+    /// Control the panels on which the `y` axis labels are drawn.
+    YLabelsAt(Edge),
+    /// Control the panels on which the `x` tick labels are drawn.
+    XTickLabelsAt(Edge),
+    /// Control the panels on which the `y` tick labels are drawn.
+    YTickLabelsAt(Edge),
+    /// Control the group name: panels can be referred to as `{name} c{column}r{row}`.
+    GroupName(String),
+}
+
+impl fmt::Display for GroupStyleOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::GroupSize { columns, rows } => write!(f, "group size={columns} by {rows}"),
+            Self::HorizontalSep(value) => write!(f, "horizontal sep={value}"),
+            Self::VerticalSep(value) => write!(f, "vertical sep={value}"),
+            Self::XLabelsAt(value) => write!(f, "xlabels at={value}"),
+            Self::YLabelsAt(value) => write!(f, "ylabels at={value}"),
+            Self::XTickLabelsAt(value) => write!(f, "xticklabels at={value}"),
+            Self::YTickLabelsAt(value) => write!(f, "yticklabels at={value}"),
+            Self::GroupName(value) => write!(f, "group name={value}"),
+        }
+    }
+}
+
+impl From<&str> for GroupStyleOption {
+    fn from(option: &str) -> Self {
+        Self::Custom(option.to_string())
+    }
+}
+
+/// Cell of a [`GroupPlot`] grid.
+#[derive(Clone, Debug)]
+enum Cell {
+    /// Panel, with its own options and plots.
+    Axis(Axis),
+    /// Empty cell, skipped in the grid.
+    Empty,
+}
+
+/// Grid of axis panels, equivalent to the PGFPlots `groupplot` environment:
+///
+/// ```text
+/// \begin{groupplot}[group style={GroupStyleOptions}, AxisOptions]
+///     \nextgroupplot[AxisOptions]
+///     % plots
+/// \end{groupplot}
+/// ```
+///
+/// Cells are filled row by row, the options of each cell [`Axis`] overriding the options
+/// shared by all the panels: adding more cells than the grid holds is an error. The
+/// `groupplots` PGFPlots library is loaded automatically.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::{
+///     length::Length,
+///     tikzpicture::{
+///         axis::Axis,
+///         groupplot::{Edge, GroupPlot, GroupStyleOption},
+///         TikzPicture,
+///     },
+/// };
+///
+/// let group = GroupPlot::new(2, 1)
+///     .group_option(GroupStyleOption::HorizontalSep(Length::Pt(20.0)))
+///     .group_option(GroupStyleOption::YTickLabelsAt(Edge::Left))
+///     .cell(Axis::new().title("left"))
+///     .unwrap()
+///     .cell(Axis::new().title("right"))
+///     .unwrap();
+/// let picture = TikzPicture::from(group);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GroupPlot {
+    group_options: Vec<GroupStyleOption>,
+    /// Options shared by all the panels.
+    shared: Axis,
+    cells: Vec<Cell>,
+    /// Name of the grid in the picture, prefixing the coordinates of the spies of the panels
+    /// unless a [`GroupStyleOption::GroupName`] is set.
+    name: Option<String>,
+}
+
+impl fmt::Display for GroupPlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\\begin{{groupplot}}[")?;
+        writeln!(
+            f,
+            "\tgroup style={{{}}},",
+            self.group_options
+                .iter()
+                .map(|option| option.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        for option in self.shared.options() {
+            writeln!(f, "\t{option},")?;
+        }
+        writeln!(f, "]")?;
+
+        for (n, cell) in self.cells.iter().enumerate() {
+            match cell {
+                Cell::Axis(axis) => {
+                    write!(f, "\\nextgroupplot")?;
+                    // Panels can not change environment: log axes are set through options.
+                    let kind = axis.kind();
+                    let options = kind
+                        .log_x()
+                        .then_some(AxisOption::XMode(Scale::Log))
+                        .into_iter()
+                        .chain(kind.log_y().then_some(AxisOption::YMode(Scale::Log)))
+                        .chain(axis.options().iter().cloned())
+                        .collect::<Vec<_>>();
+                    if !options.is_empty() {
+                        writeln!(f, "[")?;
+                        for option in options {
+                            writeln!(f, "\t{option},")?;
+                        }
+                        write!(f, "]")?;
+                    }
+                    writeln!(f)?;
+
+                    for plot in axis.plots() {
+                        writeln!(f, "{plot}")?;
+                    }
+                    axis.fmt_spy_coordinates(f, &self.panel_name(n))?;
+                }
+                Cell::Empty => writeln!(f, "\\nextgroupplot[group/empty plot]")?,
+            }
+        }
+
+        write!(f, "\\end{{groupplot}}")
+    }
+}
+
+impl GroupPlot {
+    /// Creates a new, empty grid with the given number of `columns` and `rows`.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            group_options: vec![GroupStyleOption::GroupSize { columns, rows }],
+            shared: Axis::new(),
+            cells: vec![],
+            name: None,
+        }
+    }
+
+    /// Add a group style option. This will overwrite any previous mutually exclusive option.
+    pub fn group_option(mut self, option: GroupStyleOption) -> Self {
+        self.add_group_option(option);
+        self
+    }
+
+    /// Add an option shared by all the panels. This will overwrite any previous mutually
+    /// exclusive option.
+    pub fn option(mut self, option: AxisOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Add a panel in the next cell of the grid.
+    ///
+    /// Returns an error if the grid is already full.
+    pub fn cell(mut self, axis: Axis) -> Result<Self, LayoutError> {
+        self.add_cell(axis)?;
+        Ok(self)
+    }
+
+    /// Add a group style option. This will overwrite any previous mutually exclusive option.
+    pub fn add_group_option(&mut self, option: GroupStyleOption) {
+        match option {
+            GroupStyleOption::Custom(_) => (),
+            _ => {
+                if let Some(index) = self
+                    .group_options
+                    .iter()
+                    .position(|idx| std::mem::discriminant(idx) == std::mem::discriminant(&option))
+                {
+                    self.group_options.remove(index);
+                }
+            }
+        }
+        self.group_options.push(option);
+    }
+
+    /// Add an option shared by all the panels. This will overwrite any previous mutually
+    /// exclusive option.
+    pub fn add_option(&mut self, option: AxisOption) {
+        self.shared.add_option(option);
+    }
+
+    /// Add a panel in the next cell of the grid.
+    ///
+    /// Returns an error if the grid is already full: the panel is not added.
+    pub fn add_cell(&mut self, axis: Axis) -> Result<(), LayoutError> {
+        self.push_cell(Cell::Axis(axis))
+    }
+
+    /// Leave the next cell of the grid empty.
+    ///
+    /// Returns an error if the grid is already full.
+    pub fn add_empty_cell(&mut self) -> Result<(), LayoutError> {
+        self.push_cell(Cell::Empty)
+    }
+
+    /// Add `cell` to the grid, unless it is already full.
+    fn push_cell(&mut self, cell: Cell) -> Result<(), LayoutError> {
+        self.check_cells(self.cells.len() + 1)?;
+        self.cells.push(cell);
+        Ok(())
+    }
+
+    /// Checks that `cells` cells fit in the grid.
+    fn check_cells(&self, cells: usize) -> Result<(), LayoutError> {
+        for option in &self.group_options {
+            if let GroupStyleOption::GroupSize { columns, rows } = *option {
+                if cells > columns * rows {
+                    return Err(LayoutError::GroupCells {
+                        cells,
+                        columns,
+                        rows,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the name of the grid in the picture to `name`, prefixing the coordinates of the
+    /// spies of the panels unless a [`GroupStyleOption::GroupName`] is set.
+    ///
+    /// The panels themselves are not named: the `groupplots` library names them
+    /// `{group name} c{column}r{row}` and places them relative to each other by these names.
+    pub(crate) fn name_spied(&mut self, name: &str) {
+        self.name = Some(name.to_string());
+    }
+
+    /// Returns the name of the `n`-th (0-based) cell, `{group name} c{column}r{row}`.
+    fn panel_name(&self, n: usize) -> String {
+        let group = self
+            .group_options
+            .iter()
+            .find_map(|option| match option {
+                GroupStyleOption::GroupName(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .or(self.name.as_deref())
+            .unwrap_or("group");
+        let columns = self
+            .group_options
+            .iter()
+            .find_map(|option| match option {
+                GroupStyleOption::GroupSize { columns, .. } => Some((*columns).max(1)),
+                _ => None,
+            })
+            .unwrap_or(1);
+        format!("{group} c{}r{}", n % columns + 1, n / columns + 1)
+    }
+
+    /// Returns the panels with spies, together with the prefix of the coordinates of their
+    /// spies.
+    pub(crate) fn spied_panels(&self) -> Vec<(String, &Axis)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter_map(|(n, cell)| match cell {
+                Cell::Axis(axis) if !axis.spies().is_empty() => Some((self.panel_name(n), axis)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the panels of the grid.
+    pub(crate) fn axes(&self) -> impl Iterator<Item = &Axis> {
+        self.cells.iter().filter_map(|cell| match cell {
+            Cell::Axis(axis) => Some(axis),
+            Cell::Empty => None,
+        })
+    }
+
+    /// Checks that the cells still fit in the grid, which may have been shrunk by a
+    /// [`GroupStyleOption::GroupSize`] set after adding them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LayoutError::GroupCells`] if there are more cells than `columns*rows`.
+    pub fn check_layout(&self) -> Result<(), LayoutError> {
+        self.check_cells(self.cells.len())
+    }
+
+    /// Checks that the coordinates of the panels are strictly positive on their logarithmic
+    /// dimensions, including those set by the shared options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoordinateError::NonPositiveLog`] for the first offending coordinate.
+    pub fn check(&self) -> Result<(), CoordinateError> {
+        let (shared_x, shared_y) = self.shared.log_dimensions();
+        self.axes().try_for_each(|axis| {
+            let (log_x, log_y) = axis.log_dimensions();
+            axis.check_log(shared_x || log_x, shared_y || log_y)
+        })
+    }
+
+    /// Returns a vector of [`PgfPlotsLib`]s required by the grid, each appearing once.
+    pub fn required_libs(&self) -> Vec<PgfPlotsLib> {
+        std::iter::once(PgfPlotsLib::GroupPlots)
+            .chain(self.axes().flat_map(Axis::required_libs))
+            .unique()
+            .collect()
+    }
+
+    /// Returns the minimum PGFPlots compatibility layer required by the panels.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        std::iter::once(&self.shared)
+            .chain(self.axes())
+            .map(Axis::required_pgfcompat)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns a vector of [`TikzLib`]s required by the panels, each appearing once.
+    pub fn required_tikzlibs(&self) -> Vec<TikzLib> {
+        self.axes()
+            .flat_map(Axis::required_tikzlibs)
+            .unique()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::document::tikzpicture::axis::plot::bidimensional::Plot2D;
+
+    #[test]
+    fn groupplot() {
+        let mut group = GroupPlot::new(2, 2)
+            .group_option(GroupStyleOption::XLabelsAt(Edge::Top))
+            .group_option(GroupStyleOption::XLabelsAt(Edge::Bottom))
+            .option(AxisOption::XLabel("$t$".to_string()))
+            .cell(Axis::from(Plot2D::new()).option(AxisOption::YMin(0.0)))
+            .unwrap();
+        group.add_empty_cell().unwrap();
+        group.add_cell(Axis::new()).unwrap();
+        assert_eq!(
+            r#"\begin{groupplot}[
+	group style={group size=2 by 2, xlabels at=edge bottom},
+	xlabel={$t$},
+]
+\nextgroupplot[
+	ymin={0},
+]
+	\addplot[] coordinates {
+	};
+\nextgroupplot[group/empty plot]
+\nextgroupplot
+\end{groupplot}"#,
+            group.to_string()
+        );
+        assert_eq!(vec![PgfPlotsLib::GroupPlots], group.required_libs());
+        assert_eq!(Ok(()), group.add_empty_cell());
+        assert_eq!(Ok(()), group.check_layout());
+
+        let overflow = Err(LayoutError::GroupCells {
+            cells: 5,
+            columns: 2,
+            rows: 2,
+        });
+        assert_eq!(overflow, group.add_cell(Axis::new()));
+        assert_eq!(4, group.to_string().matches("\\nextgroupplot").count());
+
+        let group = group.group_option(GroupStyleOption::GroupSize {
+            columns: 1,
+            rows: 2,
+        });
+        assert_eq!(
+            Err(LayoutError::GroupCells {
+                cells: 4,
+                columns: 1,
+                rows: 2,
+            }),
+            group.check_layout()
+        );
+    }
+}
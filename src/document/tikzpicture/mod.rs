@@ -1,8 +1,27 @@
 pub mod axis;
+pub mod groupplot;
+pub mod path;
+pub mod polar;
+pub mod smithchart;
+pub mod spy;
+pub mod ternary;
+pub mod twin;
 
-use super::preamble::PgfPlotsLib;
+use super::{
+    length::Length,
+    preamble::{PgfPlotsCompat, PgfPlotsLib, TikzLib},
+};
+use crate::error::{CoordinateError, LayoutError};
 use axis::Axis;
+use groupplot::GroupPlot;
+use itertools::Itertools;
+use path::{Path, Point};
+use polar::PolarAxis;
+use smithchart::SmithChart;
+use spy::{SpyScope, ZoomInset};
 use std::fmt;
+use ternary::TernaryAxis;
+use twin::TwinAxis;
 
 // /// Implementors of this trait represent types who can be used inside an
 // /// [`crate::document::tikzpicture::TikzPicture`] environment.
@@ -21,8 +40,15 @@ use std::fmt;
 // dyn_clone::clone_trait_object!(TikzInnerEnv);
 
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum TikzInnerEnv {
     Axis(Axis),
+    GroupPlot(GroupPlot),
+    TwinAxis(TwinAxis),
+    PolarAxis(PolarAxis),
+    TernaryAxis(TernaryAxis),
+    SmithChart(SmithChart),
+    Path(Path),
 }
 
 impl From<Axis> for TikzInnerEnv {
@@ -31,10 +57,52 @@ impl From<Axis> for TikzInnerEnv {
     }
 }
 
+impl From<GroupPlot> for TikzInnerEnv {
+    fn from(group: GroupPlot) -> Self {
+        Self::GroupPlot(group)
+    }
+}
+
+impl From<TwinAxis> for TikzInnerEnv {
+    fn from(twin: TwinAxis) -> Self {
+        Self::TwinAxis(twin)
+    }
+}
+
+impl From<PolarAxis> for TikzInnerEnv {
+    fn from(axis: PolarAxis) -> Self {
+        Self::PolarAxis(axis)
+    }
+}
+
+impl From<TernaryAxis> for TikzInnerEnv {
+    fn from(axis: TernaryAxis) -> Self {
+        Self::TernaryAxis(axis)
+    }
+}
+
+impl From<SmithChart> for TikzInnerEnv {
+    fn from(chart: SmithChart) -> Self {
+        Self::SmithChart(chart)
+    }
+}
+
+impl From<Path> for TikzInnerEnv {
+    fn from(path: Path) -> Self {
+        Self::Path(path)
+    }
+}
+
 impl fmt::Display for TikzInnerEnv {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Axis(env) => write!(f, "{env}"),
+            Self::GroupPlot(env) => write!(f, "{env}"),
+            Self::TwinAxis(env) => write!(f, "{env}"),
+            Self::PolarAxis(env) => write!(f, "{env}"),
+            Self::TernaryAxis(env) => write!(f, "{env}"),
+            Self::SmithChart(env) => write!(f, "{env}"),
+            Self::Path(path) => write!(f, "{path}"),
         }
     }
 }
@@ -45,7 +113,93 @@ impl TikzInnerEnv {
     fn required_libs(&self) -> Vec<PgfPlotsLib> {
         match self {
             Self::Axis(env) => env.required_libs(),
+            Self::GroupPlot(env) => env.required_libs(),
+            Self::TwinAxis(env) => env.required_libs(),
+            Self::PolarAxis(env) => env.required_libs(),
+            Self::TernaryAxis(env) => env.required_libs(),
+            Self::SmithChart(env) => env.required_libs(),
+            Self::Path(_) => vec![],
+        }
+    }
+
+    /// Returns the minimum PGFPlots compatibility layer required by the contained inner
+    /// environment.
+    fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        match self {
+            Self::Axis(env) => env.required_pgfcompat(),
+            Self::GroupPlot(env) => env.required_pgfcompat(),
+            Self::TwinAxis(env) => env.required_pgfcompat(),
+            Self::PolarAxis(env) => env.required_pgfcompat(),
+            Self::TernaryAxis(env) => env.required_pgfcompat(),
+            Self::SmithChart(env) => env.required_pgfcompat(),
+            Self::Path(_) => PgfPlotsCompat::Default,
+        }
+    }
+
+    /// Returns a vector of [`TikzLib`]s required by the contained inner environment.
+    fn required_tikzlibs(&self) -> Vec<TikzLib> {
+        match self {
+            Self::Axis(env) => env.required_tikzlibs(),
+            Self::GroupPlot(env) => env.required_tikzlibs(),
+            Self::TwinAxis(env) => env.required_tikzlibs(),
+            Self::PolarAxis(_) | Self::TernaryAxis(_) | Self::SmithChart(_) => vec![],
+            Self::Path(path) => path.required_tikzlibs(),
+        }
+    }
+
+    /// Checks that the axes of the contained inner environment can be laid out.
+    fn check_layout(&self) -> Result<(), LayoutError> {
+        match self {
+            Self::GroupPlot(env) => env.check_layout(),
+            Self::TwinAxis(env) => env.check_layout(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the coordinates of the contained inner environment can be drawn.
+    fn check(&self) -> Result<(), CoordinateError> {
+        match self {
+            Self::Axis(env) => env.check(),
+            Self::GroupPlot(env) => env.check(),
+            Self::TwinAxis(env) => env.check(),
+            Self::TernaryAxis(env) => env.check(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Names the contained axes with spies but no name after `name`, the name of the
+    /// environment in the picture.
+    fn name_spied(&mut self, name: &str) {
+        match self {
+            Self::Axis(env) => env.name_spied(name),
+            Self::GroupPlot(env) => env.name_spied(name),
+            Self::TwinAxis(env) => env.name_spied(name),
+            _ => (),
+        }
+    }
+
+    /// Returns whether the contained environment refers to labels, only resolved by a
+    /// second LaTeX pass.
+    fn has_references(&self) -> bool {
+        match self {
+            Self::TwinAxis(env) => env.has_references(),
+            _ => false,
+        }
+    }
+
+    /// Returns the contained axes with spies, together with the prefix of the coordinates
+    /// of their spies.
+    fn spied_axes(&self) -> Vec<(String, &Axis)> {
+        match self {
+            Self::Axis(env) => vec![env],
+            Self::GroupPlot(env) => return env.spied_panels(),
+            Self::TwinAxis(env) => env.axes().to_vec(),
+            _ => vec![],
         }
+        .into_iter()
+        .filter(|axis| !axis.spies().is_empty())
+        .map(|axis| (axis.spy_name().to_string(), axis))
+        .collect()
     }
 }
 
@@ -59,14 +213,173 @@ impl TikzInnerEnv {
 #[non_exhaustive]
 pub enum TikzPictureOption {
     /// Custom key-value pairs that have not been implemented. These will be
-    /// appended verbatim to the options of the [`Picture`].
+    /// appended verbatim to the options of the [`TikzPicture`].
     Custom(String),
+    /// Scale the whole picture by the given factor.
+    Scale(f64),
+    /// Scale the `x` coordinates by the given factor.
+    XScale(f64),
+    /// Scale the `y` coordinates by the given factor.
+    YScale(f64),
+    /// Control the vertical alignment of the picture with the surrounding text.
+    Baseline(Baseline),
+    /// Control the font of the picture text, e.g. `\small` or `\sffamily`.
+    Font(String),
+    /// Control the style of every node in the picture, e.g. `draw, circle`.
+    EveryNodeStyle(String),
+    /// Control the `x` unit vector.
+    X(UnitVector),
+    /// Control the `y` unit vector.
+    Y(UnitVector),
+    /// Control the left border of the picture bounding box.
+    TrimLeft(Trim),
+    /// Control the right border of the picture bounding box.
+    TrimRight(Trim),
+    /// Control the default width of the lines drawn in the picture.
+    LineWidth(Length),
+    /// Control the magnification, lens shape and connection lines of the spies of the
+    /// contained axes (see [`axis::Axis::spy`]).
+    SpyUsing(SpyScope),
 }
 
 impl fmt::Display for TikzPictureOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             TikzPictureOption::Custom(key) => write!(f, "{key}"),
+            TikzPictureOption::Scale(value) => write!(f, "scale={value}"),
+            TikzPictureOption::XScale(value) => write!(f, "xscale={value}"),
+            TikzPictureOption::YScale(value) => write!(f, "yscale={value}"),
+            TikzPictureOption::Baseline(value) => write!(f, "{value}"),
+            TikzPictureOption::Font(value) => write!(f, "font={{{value}}}"),
+            TikzPictureOption::EveryNodeStyle(value) => write!(f, "every node/.style={{{value}}}"),
+            TikzPictureOption::X(value) => write!(f, "x={value}"),
+            TikzPictureOption::Y(value) => write!(f, "y={value}"),
+            TikzPictureOption::TrimLeft(value) => value.fmt_side(f, "left"),
+            TikzPictureOption::TrimRight(value) => value.fmt_side(f, "right"),
+            TikzPictureOption::LineWidth(value) => write!(f, "line width={value}"),
+            TikzPictureOption::SpyUsing(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for TikzPictureOption {
+    fn from(option: &str) -> Self {
+        Self::Custom(option.to_string())
+    }
+}
+
+/// Anchor of a Ti*k*Z node, e.g. used to place a node relative to a point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Anchor {
+    /// Custom anchor, e.g. `mid west` or an angle such as `30`.
+    Custom(String),
+    Center,
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+    /// Anchor on the text baseline, horizontally centered.
+    Base,
+}
+
+impl fmt::Display for Anchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(anchor) => write!(f, "{anchor}"),
+            Self::Center => write!(f, "center"),
+            Self::North => write!(f, "north"),
+            Self::NorthEast => write!(f, "north east"),
+            Self::East => write!(f, "east"),
+            Self::SouthEast => write!(f, "south east"),
+            Self::South => write!(f, "south"),
+            Self::SouthWest => write!(f, "south west"),
+            Self::West => write!(f, "west"),
+            Self::NorthWest => write!(f, "north west"),
+            Self::Base => write!(f, "base"),
+        }
+    }
+}
+
+impl Anchor {
+    /// Returns the outer variant of the anchor (e.g. `outer north east`), which for an axis
+    /// refers to its bounding box including tick labels and axis labels.
+    pub fn outer(&self) -> Self {
+        Self::Custom(format!("outer {self}"))
+    }
+}
+
+/// Vertical alignment of a [`TikzPicture`] with the surrounding text.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Baseline {
+    /// Custom baseline, e.g. `-0.5ex` or `(node.base)`.
+    Custom(String),
+    /// Align the picture origin with the text baseline (`baseline`).
+    Origin,
+    /// Align the picture center with the text baseline.
+    Center,
+    /// Align the picture center with the math axis of the text (e.g. the middle of a `+`
+    /// sign), suited for pictures inline with math.
+    MathAxis,
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(value) => write!(f, "baseline={{{value}}}"),
+            Self::Origin => write!(f, "baseline"),
+            Self::Center => write!(f, "baseline={{(current bounding box.center)}}"),
+            Self::MathAxis => write!(
+                f,
+                "baseline={{([yshift=-0.5ex]current bounding box.center)}}"
+            ),
+        }
+    }
+}
+
+/// Ti*k*Z unit vector.
+#[derive(Clone, Copy, Debug)]
+pub enum UnitVector {
+    /// Unit vector parallel to the axis, with the given length.
+    Length(Length),
+    /// Unit vector with the given `x` and `y` components.
+    Vector(Length, Length),
+}
+
+impl fmt::Display for UnitVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(length) => write!(f, "{length}"),
+            Self::Vector(x, y) => write!(f, "{{({x},{y})}}"),
+        }
+    }
+}
+
+/// Border of a [`TikzPicture`] bounding box, used to align pictures horizontally
+/// independently of e.g. their tick labels.
+#[derive(Clone, Copy, Debug)]
+pub enum Trim {
+    /// Trim the bounding box at the axis border (`trim axis left`/`trim axis right`).
+    Axis,
+    /// Trim the bounding box at the group plot border (`trim axis group left`/
+    /// `trim axis group right`).
+    AxisGroup,
+    /// Trim the bounding box at the given `x` coordinate.
+    At(Length),
+}
+
+impl Trim {
+    /// Writes the trim option for the given `side` of the bounding box.
+    fn fmt_side(&self, f: &mut fmt::Formatter<'_>, side: &str) -> fmt::Result {
+        match self {
+            Self::Axis => write!(f, "trim axis {side}"),
+            Self::AxisGroup => write!(f, "trim axis group {side}"),
+            Self::At(value) => write!(f, "trim {side}={value}"),
         }
     }
 }
@@ -89,19 +402,32 @@ pub struct TikzPicture {
 impl fmt::Display for TikzPicture {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "\\begin{{tikzpicture}}")?;
+        // Spies can not be drawn outside of a `spy using` scope.
+        let default_spy_scope = (self.has_spies()
+            && !self
+                .options
+                .iter()
+                .any(|option| matches!(option, TikzPictureOption::SpyUsing(_))))
+        .then(|| TikzPictureOption::SpyUsing(SpyScope::default()));
         // If there are keys, print one per line. It makes it easier for a
         // human later to find keys if they are divided by lines.
-        if !self.options.is_empty() {
+        if !self.options.is_empty() || default_spy_scope.is_some() {
             writeln!(f, "[")?;
-            for key in self.options.iter() {
+            for key in self.options.iter().chain(default_spy_scope.iter()) {
                 writeln!(f, "\t{key},")?;
             }
             write!(f, "]")?;
         }
         writeln!(f)?;
 
-        for axis in self.inner_env.iter() {
-            writeln!(f, "{axis}")?;
+        for env in self.inner_env.iter() {
+            writeln!(f, "{env}")?;
+            for (prefix, axis) in env.spied_axes() {
+                for (n, spy) in axis.spies().iter().enumerate() {
+                    spy.fmt_command(f, &prefix, n + 1)?;
+                    writeln!(f)?;
+                }
+            }
         }
 
         write!(f, "\\end{{tikzpicture}}")?;
@@ -112,10 +438,9 @@ impl fmt::Display for TikzPicture {
 
 impl From<TikzInnerEnv> for TikzPicture {
     fn from(env: TikzInnerEnv) -> Self {
-        Self {
-            options: vec![],
-            inner_env: vec![env],
-        }
+        let mut picture = Self::new();
+        picture.add_env(env);
+        picture
     }
 }
 
@@ -125,66 +450,285 @@ impl From<Axis> for TikzPicture {
     }
 }
 
+impl From<GroupPlot> for TikzPicture {
+    fn from(group: GroupPlot) -> Self {
+        Self::from(TikzInnerEnv::GroupPlot(group))
+    }
+}
+
+impl From<TwinAxis> for TikzPicture {
+    fn from(twin: TwinAxis) -> Self {
+        Self::from(TikzInnerEnv::TwinAxis(twin))
+    }
+}
+
+impl From<PolarAxis> for TikzPicture {
+    fn from(axis: PolarAxis) -> Self {
+        Self::from(TikzInnerEnv::PolarAxis(axis))
+    }
+}
+
+impl From<TernaryAxis> for TikzPicture {
+    fn from(axis: TernaryAxis) -> Self {
+        Self::from(TikzInnerEnv::TernaryAxis(axis))
+    }
+}
+
+impl From<SmithChart> for TikzPicture {
+    fn from(chart: SmithChart) -> Self {
+        Self::from(TikzInnerEnv::SmithChart(chart))
+    }
+}
+
+impl From<Path> for TikzPicture {
+    fn from(path: Path) -> Self {
+        Self::from(TikzInnerEnv::Path(path))
+    }
+}
+
 impl TikzPicture {
     /// Create a new, empty picture environment.
     ///
     /// # Examples
     ///
     /// ```
-    /// use pgfplots::Picture;
+    /// use pgfplots::document::tikzpicture::TikzPicture;
     ///
-    /// let picture = Picture::new();
+    /// let picture = TikzPicture::new();
     /// ```
     pub fn new() -> Self {
         Default::default()
     }
 
-    /// Add a key to control the appearance of the picture. This will overwrite
-    /// any previous mutually exclusive key.
+    /// Add an option to control the appearance of the picture. This will overwrite
+    /// any previous mutually exclusive option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::{Baseline, TikzPicture, TikzPictureOption};
+    ///
+    /// let picture = TikzPicture::new()
+    ///     .option(TikzPictureOption::Scale(0.8))
+    ///     .option(TikzPictureOption::Baseline(Baseline::MathAxis));
+    /// ```
+    pub fn option(mut self, option: TikzPictureOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Add an option to control the appearance of the picture. This will overwrite
+    /// any previous mutually exclusive option.
     ///
     /// # Examples
     ///
     /// ```
     /// use pgfplots::document::tikzpicture::{TikzPicture, TikzPictureOption};
     ///
-    /// let mut picture = Picture::new();
-    /// picture.add_option(PictureKey::Custom(String::from("baseline")));
+    /// let mut picture = TikzPicture::new();
+    /// picture.add_option(TikzPictureOption::Font(String::from(r"\small")));
     /// ```
     pub fn add_option(&mut self, option: TikzPictureOption) {
         match option {
             TikzPictureOption::Custom(_) => (),
-            // If/whenever another variant is added, handle it the same way as
-            // Axis::add_key and Plot2D::add_key
+            _ => {
+                if let Some(index) = self
+                    .options
+                    .iter()
+                    .position(|idx| std::mem::discriminant(idx) == std::mem::discriminant(&option))
+                {
+                    self.options.remove(index);
+                }
+            }
         }
         self.options.push(option);
     }
 
-    /// Returns a vector of required PGFPlots libraries based on the type of contained [`Plot`]s.
+    /// Returns a vector of required PGFPlots libraries based on the type of contained [`Plot`]s,
+    /// each appearing once.
     pub fn required_libs(&self) -> Vec<PgfPlotsLib> {
         self.inner_env
             .iter()
             .flat_map(|env| env.required_libs())
+            .unique()
+            .collect()
+    }
+
+    /// Returns the minimum PGFPlots compatibility layer required by the contained environments.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        self.inner_env
+            .iter()
+            .map(TikzInnerEnv::required_pgfcompat)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns a vector of required Ti*k*Z libraries based on the contained environments,
+    /// each appearing once.
+    pub fn required_tikzlibs(&self) -> Vec<TikzLib> {
+        self.inner_env
+            .iter()
+            .flat_map(|env| env.required_tikzlibs())
+            .unique()
             .collect()
     }
 
+    /// Checks that the coordinates of the contained environments can be drawn, e.g. that
+    /// ternary coordinates sum to the axis total or that coordinates on logarithmic axes are
+    /// strictly positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`CoordinateError`] of the first invalid coordinate.
+    pub fn check(&self) -> Result<(), CoordinateError> {
+        self.inner_env.iter().try_for_each(TikzInnerEnv::check)
+    }
+
+    /// Checks that the axes of the contained environments can be laid out, e.g. that the
+    /// cells of a [`GroupPlot`] fit in its grid or that the left axis of a [`TwinAxis`] sets
+    /// the shared `x` limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`LayoutError`] of the first environment that can not be laid out.
+    pub fn check_layout(&self) -> Result<(), LayoutError> {
+        self.inner_env
+            .iter()
+            .try_for_each(TikzInnerEnv::check_layout)
+    }
+
+    /// Returns whether the picture refers to labels, e.g. the combined legend of a
+    /// [`TwinAxis`], which are only resolved by a second LaTeX pass.
+    pub(crate) fn has_references(&self) -> bool {
+        self.inner_env.iter().any(TikzInnerEnv::has_references)
+    }
+
     /// Add a new [`TikzInnerEnv`] to the Ti*k*Z picture.
-    pub fn add_env(&mut self, env: TikzInnerEnv) {
+    ///
+    /// The contained axes with spies but no name are named after the position of the
+    /// environment in the picture, e.g. `axis 2` for the second one.
+    pub fn add_env(&mut self, mut env: TikzInnerEnv) {
+        env.name_spied(&self.default_name());
         self.inner_env.push(env);
     }
 
     /// Add a new [`Axis`] environment to the Ti*k*Z picture.
     pub fn add_axis(&mut self, axis: Axis) {
-        self.inner_env.push(axis.into());
+        self.add_env(axis.into());
+    }
+
+    /// Add a new [`Path`] to the Ti*k*Z picture, e.g. to annotate the contained axes.
+    pub fn add_path(&mut self, path: Path) {
+        self.add_env(path.into());
+    }
+
+    /// Add a new [`Axis`] environment to the Ti*k*Z picture, together with a [`ZoomInset`]
+    /// re-rendering a region of its data in a smaller axis. The region is outlined in the
+    /// [`Axis`] and connected to the inset by lines.
+    ///
+    /// The [`Axis`] is named after its position in the picture unless already named, e.g.
+    /// `axis 1`: the inset is named after it, e.g. `axis 1 zoom`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::{
+    ///     axis::{plot::bidimensional::Plot2D, Axis},
+    ///     spy::ZoomInset,
+    ///     Anchor, TikzPicture,
+    /// };
+    ///
+    /// let plot = Plot2D::new().coordinates(
+    ///     (0..100)
+    ///         .map(|i| (f64::from(i) / 10.0, (f64::from(i) / 10.0).sin()).into())
+    ///         .collect::<Vec<_>>(),
+    /// );
+    /// let mut picture = TikzPicture::new();
+    /// picture.add_zoom_inset(
+    ///     Axis::from(plot),
+    ///     ZoomInset::new((1.0, 2.0), (0.8, 1.0)).corner(Anchor::SouthWest),
+    /// );
+    /// ```
+    pub fn add_zoom_inset(&mut self, mut axis: Axis, inset: ZoomInset) {
+        if axis.name_ref().is_none() {
+            axis.set_name(&self.default_name());
+        }
+        let name = axis.spy_name().to_string();
+        let zoom = format!("{name} zoom");
+        let inset_axis = axis.zoomed(&inset, &name, &zoom);
+
+        let ((x0, x1), (y0, y1)) = (inset.x, inset.y);
+        axis.add_plot(
+            Path::draw()
+                .move_to(Point::Axis(x0, y0))
+                .rectangle(Point::Axis(x1, y1))
+                .move_to(Point::Axis(x0, y1))
+                .coordinate(&format!("{zoom} north west"))
+                .move_to(Point::Axis(x1, y0))
+                .coordinate(&format!("{zoom} south east"))
+                .into(),
+        );
+        self.add_axis(axis);
+        self.add_axis(inset_axis);
+        self.add_path(
+            Path::draw()
+                .move_to(Point::Named(format!("{zoom} north west")))
+                .line_to(Point::Anchor(zoom.clone(), Anchor::NorthWest))
+                .move_to(Point::Named(format!("{zoom} south east")))
+                .line_to(Point::Anchor(zoom.clone(), Anchor::SouthEast)),
+        );
+    }
+
+    /// Add a new [`Axis`] environment to the Ti*k*Z picture, together with a [`ZoomInset`]
+    /// (see [`TikzPicture::add_zoom_inset`]).
+    pub fn zoom_inset(mut self, axis: Axis, inset: ZoomInset) -> Self {
+        self.add_zoom_inset(axis, inset);
+        self
+    }
+
+    /// Returns the default name of the next environment added to the picture, unique in
+    /// the picture: `axis {n}`, `n` being its (1-based) position.
+    fn default_name(&self) -> String {
+        format!("axis {}", self.inner_env.len() + 1)
+    }
+
+    /// Returns whether any contained [`Axis`] has spies.
+    fn has_spies(&self) -> bool {
+        self.inner_env
+            .iter()
+            .any(|env| !env.spied_axes().is_empty())
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{
-        document::tikzpicture::axis::Axis,
-        libs::statistics::histogram::Histogram,
-    };
+    use crate::{document::tikzpicture::axis::Axis, libs::statistics::histogram::Histogram};
+
+    #[test]
+    fn options() {
+        let picture = TikzPicture::new()
+            .option(TikzPictureOption::Scale(2.0))
+            .option(TikzPictureOption::X(UnitVector::Vector(
+                Length::Cm(1.0),
+                Length::Cm(0.5),
+            )))
+            .option(TikzPictureOption::TrimLeft(Trim::Axis))
+            .option(TikzPictureOption::TrimRight(Trim::At(Length::Pt(10.0))))
+            .option(TikzPictureOption::Scale(0.5))
+            .option("transform shape".into());
+        assert_eq!(
+            r#"\begin{tikzpicture}[
+	x={(1cm,0.5cm)},
+	trim axis left,
+	trim right=10pt,
+	scale=0.5,
+	transform shape,
+]
+\end{tikzpicture}"#,
+            picture.to_string()
+        );
+    }
 
     #[test]
     fn required_libs() {
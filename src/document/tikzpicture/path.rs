@@ -0,0 +1,493 @@
+use super::Anchor;
+use crate::document::{length::Length, preamble::TikzLib};
+use std::fmt;
+
+/// Point of a Ti*k*Z [`Path`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Point {
+    /// Custom coordinate, written verbatim between parentheses.
+    Custom(String),
+    /// Canvas coordinate (`(x,y)`).
+    Canvas(Length, Length),
+    /// Axis coordinate, in the data units of the enclosing [`super::axis::Axis`]
+    /// (`(axis cs:x,y)`).
+    Axis(f64, f64),
+    /// Offset from the previous point of the path (`++(x,y)`).
+    Relative(Length, Length),
+    /// Named coordinate or node (`(name)`), e.g. defined by [`Path::coordinate`].
+    Named(String),
+    /// Anchor of a named node (`(name.anchor)`).
+    Anchor(String, Anchor),
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(point) => write!(f, "({point})"),
+            Self::Canvas(x, y) => write!(f, "({x},{y})"),
+            Self::Axis(x, y) => write!(f, "(axis cs:{x},{y})"),
+            Self::Relative(x, y) => write!(f, "++({x},{y})"),
+            Self::Named(name) => write!(f, "({name})"),
+            Self::Anchor(name, anchor) => write!(f, "({name}.{anchor})"),
+        }
+    }
+}
+
+impl From<(Length, Length)> for Point {
+    fn from((x, y): (Length, Length)) -> Self {
+        Self::Canvas(x, y)
+    }
+}
+
+/// Arrow tip, drawn at the start or at the end of a [`Path`].
+///
+/// Arrow tips are defined by the `arrows.meta` Ti*k*Z library, which is loaded automatically.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ArrowTip {
+    /// Custom arrow tip, e.g. `Triangle[open]`.
+    Custom(String),
+    /// Stealth fighter-like arrow tip (`Stealth`).
+    Stealth,
+    /// LaTeX arrow tip (`Latex`).
+    Latex,
+    /// TeX `\to` arrow tip (`To`).
+    To,
+    /// Bar arrow tip, e.g. for dimension lines (`Bar`).
+    Bar,
+    /// Open triangle arrow tip (`Triangle[open]`).
+    OpenTriangle,
+}
+
+impl fmt::Display for ArrowTip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(tip) => write!(f, "{tip}"),
+            Self::Stealth => write!(f, "Stealth"),
+            Self::Latex => write!(f, "Latex"),
+            Self::To => write!(f, "To"),
+            Self::Bar => write!(f, "Bar"),
+            Self::OpenTriangle => write!(f, "Triangle[open]"),
+        }
+    }
+}
+
+/// Ti*k*Z options passed to a [`Path`].
+///
+/// The [`PathOption::Custom`] variant is provided to add unimplemented keys and will be
+/// written verbatim in the options of the [`Path`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PathOption {
+    /// Custom key-value pairs that have not been implemented.
+    Custom(String),
+    /// Control the arrow tips drawn at the start and at the end of the path.
+    Arrows {
+        start: Option<ArrowTip>,
+        end: Option<ArrowTip>,
+    },
+    /// Control the color of the path, e.g. `red` or `blue!50!black`.
+    Color(String),
+    /// Control the fill color of the path.
+    Fill(String),
+    /// Control the line width of the path.
+    LineWidth(Length),
+    /// Draw a dashed line.
+    Dashed,
+    /// Draw a dotted line.
+    Dotted,
+}
+
+impl fmt::Display for PathOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::Arrows { start, end } => {
+                if let Some(start) = start {
+                    write!(f, "{start}")?;
+                }
+                write!(f, "-")?;
+                if let Some(end) = end {
+                    write!(f, "{end}")?;
+                }
+                Ok(())
+            }
+            Self::Color(value) => write!(f, "color={value}"),
+            Self::Fill(value) => write!(f, "fill={value}"),
+            Self::LineWidth(value) => write!(f, "line width={value}"),
+            Self::Dashed => write!(f, "dashed"),
+            Self::Dotted => write!(f, "dotted"),
+        }
+    }
+}
+
+impl From<&str> for PathOption {
+    fn from(option: &str) -> Self {
+        Self::Custom(option.to_string())
+    }
+}
+
+/// Text node placed along a [`Path`].
+///
+/// ```text
+/// node[anchor=anchor, pos=pos, options] {text}
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Node {
+    /// Node text.
+    text: String,
+    /// Node name, used to refer to the node with [`Point::Named`] or [`Point::Anchor`].
+    name: Option<String>,
+    /// Node anchor placed at the current point of the path.
+    anchor: Option<Anchor>,
+    /// Position of the node along the previous path segment, from `0` (start) to `1` (end).
+    pos: Option<f64>,
+    /// Custom node options, e.g. `draw` or `font=\small`.
+    options: Vec<String>,
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "node")?;
+
+        let options = self
+            .anchor
+            .iter()
+            .map(|anchor| format!("anchor={anchor}"))
+            .chain(self.pos.iter().map(|pos| format!("pos={pos}")))
+            .chain(self.options.iter().cloned())
+            .collect::<Vec<_>>();
+        if !options.is_empty() {
+            write!(f, "[{}]", options.join(", "))?;
+        }
+        if let Some(name) = &self.name {
+            write!(f, " ({name})")?;
+        }
+
+        write!(f, " {{{}}}", self.text)
+    }
+}
+
+impl Node {
+    /// Constructs a new [`Node`] with the given text.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the node name.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the node anchor placed at the current point of the path.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = Some(anchor);
+        self
+    }
+
+    /// Set the position of the node along the previous path segment, from `0` (start) to
+    /// `1` (end).
+    pub fn pos(mut self, pos: f64) -> Self {
+        self.pos = Some(pos);
+        self
+    }
+
+    /// Add a custom node option.
+    pub fn option(mut self, option: &str) -> Self {
+        self.options.push(option.to_string());
+        self
+    }
+}
+
+/// Segment of a Ti*k*Z [`Path`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Segment {
+    /// Move to the point, without drawing.
+    MoveTo(Point),
+    /// Straight line to the point (`-- point`).
+    LineTo(Point),
+    /// Bézier curve to `to`, with one or two control points
+    /// (`.. controls first and second .. to`).
+    CurveTo {
+        first: Point,
+        second: Option<Point>,
+        to: Point,
+    },
+    /// Rectangle from the current point to the opposite corner (`rectangle corner`).
+    Rectangle(Point),
+    /// Circle centered at the current point, with the given radius (`circle[radius=r]`).
+    Circle(Length),
+    /// Text node placed at the current point or along the previous segment.
+    Node(Node),
+    /// Named coordinate at the current point (`coordinate (name)`).
+    Coordinate(String),
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MoveTo(point) => write!(f, "{point}"),
+            Self::LineTo(point) => write!(f, "-- {point}"),
+            Self::CurveTo { first, second, to } => {
+                write!(f, ".. controls {first} ")?;
+                if let Some(second) = second {
+                    write!(f, "and {second} ")?;
+                }
+                write!(f, ".. {to}")
+            }
+            Self::Rectangle(corner) => write!(f, "rectangle {corner}"),
+            Self::Circle(radius) => write!(f, "circle[radius={radius}]"),
+            Self::Node(node) => write!(f, "{node}"),
+            Self::Coordinate(name) => write!(f, "coordinate ({name})"),
+        }
+    }
+}
+
+/// Ti*k*Z path command, determining what is done with the [`Path`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PathCommand {
+    /// Stroke the path (`\draw`).
+    #[default]
+    Draw,
+    /// Fill the path (`\fill`).
+    Fill,
+    /// Fill and stroke the path (`\filldraw`).
+    FillDraw,
+    /// Neither fill nor stroke the path, e.g. to only place nodes or coordinates (`\path`).
+    Path,
+}
+
+impl fmt::Display for PathCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Draw => "\\draw",
+            Self::Fill => "\\fill",
+            Self::FillDraw => "\\filldraw",
+            Self::Path => "\\path",
+        })
+    }
+}
+
+/// Ti*k*Z path, usable both inside an [`super::axis::Axis`] (e.g. to annotate plots using
+/// [`Point::Axis`] coordinates) and directly inside a [`super::TikzPicture`].
+///
+/// ```text
+/// \draw[options] (0,0) -- (1,1) node[anchor=west] {text};
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::tikzpicture::{
+///     path::{ArrowTip, Node, Path, PathOption, Point},
+///     Anchor,
+/// };
+///
+/// let path = Path::draw()
+///     .option(PathOption::Arrows {
+///         start: None,
+///         end: Some(ArrowTip::Stealth),
+///     })
+///     .move_to(Point::Axis(1.0, 2.0))
+///     .line_to(Point::Axis(3.0, 4.0))
+///     .node(Node::new("peak").anchor(Anchor::West));
+/// assert_eq!(
+///     r"\draw[-Stealth] (axis cs:1,2) -- (axis cs:3,4) node[anchor=west] {peak};",
+///     path.to_string()
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    command: PathCommand,
+    options: Vec<PathOption>,
+    segments: Vec<Segment>,
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.command)?;
+        if !self.options.is_empty() {
+            write!(
+                f,
+                "[{}]",
+                self.options
+                    .iter()
+                    .map(|option| option.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        for segment in &self.segments {
+            write!(f, " {segment}")?;
+        }
+
+        write!(f, ";")
+    }
+}
+
+impl Path {
+    /// Creates a new, empty path with the given [`PathCommand`].
+    pub fn new(command: PathCommand) -> Self {
+        Self {
+            command,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new, empty stroked path (`\draw`).
+    pub fn draw() -> Self {
+        Self::new(PathCommand::Draw)
+    }
+
+    /// Creates a new, empty filled path (`\fill`).
+    pub fn fill() -> Self {
+        Self::new(PathCommand::Fill)
+    }
+
+    /// Add an option to control the appearance of the path. This will overwrite any previous
+    /// mutually exclusive option.
+    pub fn option(mut self, option: PathOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Move to the point, without drawing.
+    pub fn move_to<P>(self, point: P) -> Self
+    where
+        P: Into<Point>,
+    {
+        self.segment(Segment::MoveTo(point.into()))
+    }
+
+    /// Draw a straight line to the point.
+    pub fn line_to<P>(self, point: P) -> Self
+    where
+        P: Into<Point>,
+    {
+        self.segment(Segment::LineTo(point.into()))
+    }
+
+    /// Draw a cubic Bézier curve to `to`, with control points `first` and `second`.
+    pub fn curve_to<P>(self, first: P, second: P, to: P) -> Self
+    where
+        P: Into<Point>,
+    {
+        self.segment(Segment::CurveTo {
+            first: first.into(),
+            second: Some(second.into()),
+            to: to.into(),
+        })
+    }
+
+    /// Draw a rectangle from the current point to the opposite `corner`.
+    pub fn rectangle<P>(self, corner: P) -> Self
+    where
+        P: Into<Point>,
+    {
+        self.segment(Segment::Rectangle(corner.into()))
+    }
+
+    /// Draw a circle centered at the current point, with the given `radius`.
+    pub fn circle(self, radius: Length) -> Self {
+        self.segment(Segment::Circle(radius))
+    }
+
+    /// Place a text node at the current point or along the previous segment.
+    pub fn node(self, node: Node) -> Self {
+        self.segment(Segment::Node(node))
+    }
+
+    /// Name the current point, which can then be referred to with [`Point::Named`].
+    pub fn coordinate(self, name: &str) -> Self {
+        self.segment(Segment::Coordinate(name.to_string()))
+    }
+
+    /// Add a [`Segment`] to the path.
+    pub fn segment(mut self, segment: Segment) -> Self {
+        self.add_segment(segment);
+        self
+    }
+
+    /// Add an option to control the appearance of the path. This will overwrite any previous
+    /// mutually exclusive option.
+    pub fn add_option(&mut self, option: PathOption) {
+        match option {
+            PathOption::Custom(_) => (),
+            _ => {
+                if let Some(index) = self
+                    .options
+                    .iter()
+                    .position(|idx| std::mem::discriminant(idx) == std::mem::discriminant(&option))
+                {
+                    self.options.remove(index);
+                }
+            }
+        }
+        self.options.push(option);
+    }
+
+    /// Add a [`Segment`] to the path.
+    pub fn add_segment(&mut self, segment: Segment) {
+        self.segments.push(segment);
+    }
+
+    /// Returns the Ti*k*Z libraries required by the path.
+    pub fn required_tikzlibs(&self) -> Vec<TikzLib> {
+        if self
+            .options
+            .iter()
+            .any(|option| matches!(option, PathOption::Arrows { .. }))
+        {
+            vec![TikzLib::ArrowsMeta]
+        } else {
+            vec![]
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn path() {
+        let path = Path::fill()
+            .option(PathOption::Color("red".to_string()))
+            .option(PathOption::Color("blue".to_string()))
+            .move_to((Length::Cm(0.0), Length::Cm(0.0)))
+            .rectangle(Point::Relative(Length::Cm(1.0), Length::Mm(20.0)))
+            .coordinate("corner")
+            .move_to(Point::Anchor("corner".to_string(), Anchor::NorthEast))
+            .circle(Length::Pt(5.0))
+            .curve_to(
+                (Length::Cm(1.0), Length::Cm(1.0)),
+                (Length::Cm(2.0), Length::Cm(1.0)),
+                (Length::Cm(3.0), Length::Cm(0.0)),
+            );
+        assert_eq!(
+            r"\fill[color=blue] (0cm,0cm) rectangle ++(1cm,20mm) coordinate (corner) (corner.north east) circle[radius=5pt] .. controls (1cm,1cm) and (2cm,1cm) .. (3cm,0cm);",
+            path.to_string()
+        );
+        assert!(path.required_tikzlibs().is_empty());
+
+        let path = Path::draw()
+            .option(PathOption::Arrows {
+                start: Some(ArrowTip::Bar),
+                end: Some(ArrowTip::Bar),
+            })
+            .move_to(Point::Named("a".to_string()))
+            .line_to(Point::Named("b".to_string()))
+            .node(Node::new("$d$").name("label").pos(0.5).option("above"));
+        assert_eq!(
+            r"\draw[Bar-Bar] (a) -- (b) node[pos=0.5, above] (label) {$d$};",
+            path.to_string()
+        );
+        assert_eq!(vec![TikzLib::ArrowsMeta], path.required_tikzlibs());
+    }
+}
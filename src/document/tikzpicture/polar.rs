@@ -0,0 +1,366 @@
+use super::axis::plot::bidimensional::PlotOption;
+use crate::document::preamble::{PgfPlotsCompat, PgfPlotsLib, StyleRef};
+use std::fmt;
+
+/// Unit of the angles of a [`PolarAxis`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AngleUnit {
+    #[default]
+    Degrees,
+    Radians,
+}
+
+/// PGFPlots options passed to the [`PolarAxis`] environment.
+///
+/// The [`PolarAxisOption::Custom`] variant is provided to add unimplemented keys and will be
+/// written verbatim in the options of the [`PolarAxis`] environment.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum PolarAxisOption {
+    /// Custom key-value pairs that have not been implemented.
+    Custom(String),
+    /// Control the unit of the angles of the plot coordinates.
+    AngleUnit(AngleUnit),
+    /// Control the minimum angle, in degrees: together with [`PolarAxisOption::AngleMax`],
+    /// allows to draw a partial polar axis (e.g. a half circle).
+    AngleMin(f64),
+    /// Control the maximum angle, in degrees.
+    AngleMax(f64),
+    /// Control the minimum radius.
+    RadiusMin(f64),
+    /// Control the maximum radius.
+    RadiusMax(f64),
+    /// Label the angle ticks in degrees (e.g. `90°`).
+    DegreeTickLabels,
+    /// Control the angle ticks, in degrees.
+    AngleTicks(Vec<f64>),
+    /// Control the radius ticks.
+    RadiusTicks(Vec<f64>),
+    /// Control the title of the axis environment.
+    Title(String),
+    /// Apply a named style registered in the document preamble. Unlike the other
+    /// variants, multiple styles can be applied to the same axis.
+    Style(StyleRef),
+}
+
+impl fmt::Display for PolarAxisOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::AngleUnit(AngleUnit::Degrees) => write!(f, "data cs=polar"),
+            Self::AngleUnit(AngleUnit::Radians) => write!(f, "data cs=polarrad"),
+            Self::AngleMin(value) => write!(f, "xmin={{{value}}}"),
+            Self::AngleMax(value) => write!(f, "xmax={{{value}}}"),
+            Self::RadiusMin(value) => write!(f, "ymin={{{value}}}"),
+            Self::RadiusMax(value) => write!(f, "ymax={{{value}}}"),
+            Self::DegreeTickLabels => {
+                write!(f, "xticklabel={{$\\pgfmathprintnumber{{\\tick}}^\\circ$}}")
+            }
+            Self::AngleTicks(ticks) => write!(f, "xtick={{{}}}", join(ticks)),
+            Self::RadiusTicks(ticks) => write!(f, "ytick={{{}}}", join(ticks)),
+            Self::Title(value) => write!(f, "title={{{value}}}"),
+            Self::Style(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for PolarAxisOption {
+    fn from(option: &str) -> Self {
+        Self::Custom(option.to_string())
+    }
+}
+
+/// Joins the ticks with commas.
+fn join(ticks: &[f64]) -> String {
+    ticks
+        .iter()
+        .map(|tick| tick.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Coordinate in a [`PolarPlot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PolarCoordinate {
+    /// Angle, in the [`AngleUnit`] of the [`PolarAxis`].
+    pub angle: f64,
+    pub radius: f64,
+}
+
+impl fmt::Display for PolarCoordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.angle, self.radius)
+    }
+}
+
+impl From<(f64, f64)> for PolarCoordinate {
+    /// Conversion from an `(angle, radius)` tuple into a polar coordinate.
+    fn from((angle, radius): (f64, f64)) -> Self {
+        Self { angle, radius }
+    }
+}
+
+/// Type of a [`PolarPlot`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PolarPlotType {
+    /// Coordinates joined by straight lines.
+    #[default]
+    Line,
+    /// Coordinates drawn as marks only.
+    Marks,
+    /// Closed area enclosed by the coordinates, filled (e.g. an antenna pattern).
+    Area,
+}
+
+/// Plot inside a [`PolarAxis`].
+///
+/// ```text
+/// \addplot[PlotOptions] coordinates {
+///     (angle,radius)
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PolarPlot {
+    plot_type: PolarPlotType,
+    options: Vec<PlotOption>,
+    coordinates: Vec<PolarCoordinate>,
+}
+
+impl fmt::Display for PolarPlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plot_type = match self.plot_type {
+            PolarPlotType::Line => None,
+            PolarPlotType::Marks => Some("only marks"),
+            PolarPlotType::Area => Some("fill, fill opacity=0.5"),
+        };
+
+        write!(f, "\t\\addplot[")?;
+        if plot_type.is_some() || !self.options.is_empty() {
+            writeln!(f)?;
+            if let Some(plot_type) = plot_type {
+                writeln!(f, "\t\t{plot_type},")?;
+            }
+            for option in self.options.iter() {
+                writeln!(f, "\t\t{option},")?;
+            }
+            write!(f, "\t")?;
+        }
+        writeln!(f, "] coordinates {{")?;
+
+        for coordinate in self.coordinates.iter() {
+            writeln!(f, "\t\t{coordinate}")?;
+        }
+
+        match self.plot_type {
+            PolarPlotType::Area => write!(f, "\t}} -- cycle;"),
+            _ => write!(f, "\t}};"),
+        }
+    }
+}
+
+impl PolarPlot {
+    /// Creates a new, empty polar plot of the given [`PolarPlotType`].
+    pub fn new(plot_type: PolarPlotType) -> Self {
+        Self {
+            plot_type,
+            ..Default::default()
+        }
+    }
+
+    /// Add an option to control the appearance of the plot, e.g. its color with a
+    /// [`PlotOption::Custom`] or a [`PlotOption::Style`].
+    pub fn option(mut self, option: PlotOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Set the plot coordinates.
+    pub fn coordinates<C>(mut self, coordinates: C) -> Self
+    where
+        C: IntoIterator,
+        C::Item: Into<PolarCoordinate>,
+    {
+        self.coordinates = coordinates.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add an option to control the appearance of the plot.
+    pub fn add_option(&mut self, option: PlotOption) {
+        self.options.push(option);
+    }
+
+    /// Pushes plot coordinate.
+    pub fn add_coordinate<C>(&mut self, coordinate: C)
+    where
+        C: Into<PolarCoordinate>,
+    {
+        self.coordinates.push(coordinate.into());
+    }
+}
+
+/// Polar axis environment, equivalent to the PGFPlots `polaraxis` environment:
+///
+/// ```text
+/// \begin{polaraxis}[PolarAxisOptions]
+///     % polar plots
+/// \end{polaraxis}
+/// ```
+///
+/// The `polar` PGFPlots library is loaded automatically.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::tikzpicture::{
+///     polar::{PolarAxis, PolarAxisOption, PolarPlot, PolarPlotType},
+///     TikzPicture,
+/// };
+///
+/// let pattern = PolarPlot::new(PolarPlotType::Area).coordinates(
+///     (0..360).map(|angle| (f64::from(angle), 1.0 + f64::from(angle).to_radians().cos())),
+/// );
+/// let axis = PolarAxis::new()
+///     .option(PolarAxisOption::DegreeTickLabels)
+///     .option(PolarAxisOption::RadiusMax(2.0))
+///     .plot(pattern);
+/// let picture = TikzPicture::from(axis);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PolarAxis {
+    options: Vec<PolarAxisOption>,
+    plots: Vec<PolarPlot>,
+}
+
+impl fmt::Display for PolarAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\\begin{{polaraxis}}")?;
+        if !self.options.is_empty() {
+            writeln!(f, "[")?;
+            for option in self.options.iter() {
+                writeln!(f, "\t{option},")?;
+            }
+            write!(f, "]")?;
+        }
+        writeln!(f)?;
+
+        for plot in self.plots.iter() {
+            writeln!(f, "{plot}")?;
+        }
+
+        write!(f, "\\end{{polaraxis}}")
+    }
+}
+
+impl From<PolarPlot> for PolarAxis {
+    fn from(plot: PolarPlot) -> Self {
+        Self::new().plot(plot)
+    }
+}
+
+impl PolarAxis {
+    /// Creates a new, empty polar axis environment.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add an option to control the appearance of the axis. This will overwrite any previous
+    /// mutually exclusive option.
+    pub fn option(mut self, option: PolarAxisOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Add a [`PolarPlot`] to the axis.
+    pub fn plot(mut self, plot: PolarPlot) -> Self {
+        self.add_plot(plot);
+        self
+    }
+
+    /// Add an option to control the appearance of the axis. This will overwrite any previous
+    /// mutually exclusive option.
+    pub fn add_option(&mut self, option: PolarAxisOption) {
+        match option {
+            PolarAxisOption::Custom(_) | PolarAxisOption::Style(_) => (),
+            _ => {
+                if let Some(index) = self
+                    .options
+                    .iter()
+                    .position(|idx| std::mem::discriminant(idx) == std::mem::discriminant(&option))
+                {
+                    self.options.remove(index);
+                }
+            }
+        }
+        self.options.push(option);
+    }
+
+    /// Add a [`PolarPlot`] to the axis.
+    pub fn add_plot(&mut self, plot: PolarPlot) {
+        self.plots.push(plot);
+    }
+
+    /// Returns a vector of [`PgfPlotsLib`]s required by the axis.
+    pub fn required_libs(&self) -> Vec<PgfPlotsLib> {
+        vec![PgfPlotsLib::Polar]
+    }
+
+    /// Returns the minimum PGFPlots compatibility layer required by the options of the
+    /// contained plots.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        self.plots
+            .iter()
+            .flat_map(|plot| &plot.options)
+            .map(PlotOption::required_pgfcompat)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::document::{length::BarLength, tikzpicture::axis::plot::bidimensional::Type2D};
+
+    #[test]
+    fn polar_axis() {
+        let axis = PolarAxis::new()
+            .option(PolarAxisOption::AngleUnit(AngleUnit::Radians))
+            .option(PolarAxisOption::RadiusMax(1.0))
+            .option(PolarAxisOption::RadiusMax(2.0))
+            .plot(PolarPlot::new(PolarPlotType::Area).coordinates([(0.0, 1.0), (3.0, 2.0)]))
+            .plot(
+                PolarPlot::new(PolarPlotType::Line)
+                    .option(PlotOption::Custom("red".to_string()))
+                    .coordinates([(0.0, 0.5)]),
+            );
+        assert_eq!(
+            r#"\begin{polaraxis}[
+	data cs=polarrad,
+	ymax={2},
+]
+	\addplot[
+		fill, fill opacity=0.5,
+	] coordinates {
+		(0,1)
+		(3,2)
+	} -- cycle;
+	\addplot[
+		red,
+	] coordinates {
+		(0,0.5)
+	};
+\end{polaraxis}"#,
+            axis.to_string()
+        );
+        assert_eq!(vec![PgfPlotsLib::Polar], axis.required_libs());
+        assert_eq!(PgfPlotsCompat::Default, axis.required_pgfcompat());
+
+        let axis = axis.plot(
+            PolarPlot::new(PolarPlotType::Line).option(PlotOption::Type2D(Type2D::YBar {
+                bar_width: BarLength::Axis(0.1),
+                bar_shift: BarLength::Axis(0.0),
+            })),
+        );
+        assert_eq!(PgfPlotsCompat::V1_7, axis.required_pgfcompat());
+    }
+}
@@ -0,0 +1,309 @@
+use super::axis::{plot::bidimensional::PlotOption, Ticks};
+use crate::document::preamble::{PgfPlotsCompat, PgfPlotsLib, StyleRef};
+use std::fmt;
+
+/// PGFPlots options passed to the [`SmithChart`] environment.
+///
+/// The [`SmithChartOption::Custom`] variant is provided to add unimplemented keys and will be
+/// written verbatim in the options of the [`SmithChart`] environment.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum SmithChartOption {
+    /// Custom key-value pairs that have not been implemented.
+    Custom(String),
+    /// Control the constant resistance circles, as normalized resistances.
+    RealTicks(Ticks),
+    /// Control the constant reactance arcs, as normalized reactances.
+    ImaginaryTicks(Ticks),
+    /// Control the title of the chart.
+    Title(String),
+    /// Apply a named style registered in the document preamble. Unlike the other
+    /// variants, multiple styles can be applied to the same chart.
+    Style(StyleRef),
+}
+
+impl fmt::Display for SmithChartOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::RealTicks(value) => write!(f, "xtick={{{value}}}"),
+            Self::ImaginaryTicks(value) => write!(f, "ytick={{{value}}}"),
+            Self::Title(value) => write!(f, "title={{{value}}}"),
+            Self::Style(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for SmithChartOption {
+    fn from(option: &str) -> Self {
+        Self::Custom(option.to_string())
+    }
+}
+
+/// Complex number, coordinate of a [`SmithPlot`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex {
+    /// Real part.
+    pub re: f64,
+    /// Imaginary part.
+    pub im: f64,
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.re, self.im)
+    }
+}
+
+impl From<(f64, f64)> for Complex {
+    /// Conversion from a `(re, im)` tuple into a complex number.
+    fn from((re, im): (f64, f64)) -> Self {
+        Self { re, im }
+    }
+}
+
+impl Complex {
+    /// Creates a new complex number.
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+}
+
+/// Interpretation of the coordinates of a [`SmithPlot`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputMode {
+    /// Normalized impedance `z = Z / Z0`.
+    #[default]
+    Impedance,
+    /// Reflection coefficient `Γ = (z - 1) / (z + 1)`, inside the unit disk.
+    ReflectionCoefficient,
+}
+
+/// Plot inside a [`SmithChart`].
+///
+/// ```text
+/// \addplot[PlotOptions] coordinates {
+///     (re,im)
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SmithPlot {
+    mode: InputMode,
+    options: Vec<PlotOption>,
+    coordinates: Vec<Complex>,
+}
+
+impl fmt::Display for SmithPlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\t\\addplot[")?;
+        if self.mode == InputMode::ReflectionCoefficient || !self.options.is_empty() {
+            writeln!(f)?;
+            if self.mode == InputMode::ReflectionCoefficient {
+                writeln!(f, "\t\tis smithchart cs,")?;
+            }
+            for option in self.options.iter() {
+                writeln!(f, "\t\t{option},")?;
+            }
+            write!(f, "\t")?;
+        }
+        writeln!(f, "] coordinates {{")?;
+
+        for coordinate in self.coordinates.iter() {
+            writeln!(f, "\t\t{coordinate}")?;
+        }
+
+        write!(f, "\t}};")
+    }
+}
+
+impl SmithPlot {
+    /// Creates a new, empty plot whose coordinates are interpreted according to the given
+    /// [`InputMode`].
+    pub fn new(mode: InputMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// Add an option to control the appearance of the plot, e.g. its color with a
+    /// [`PlotOption::Custom`] or a [`PlotOption::Style`].
+    pub fn option(mut self, option: PlotOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Set the plot coordinates, as [`Complex`] numbers or `(re, im)` pairs.
+    pub fn coordinates<C>(mut self, coordinates: C) -> Self
+    where
+        C: IntoIterator,
+        C::Item: Into<Complex>,
+    {
+        self.coordinates = coordinates.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add an option to control the appearance of the plot.
+    pub fn add_option(&mut self, option: PlotOption) {
+        self.options.push(option);
+    }
+
+    /// Pushes plot coordinate.
+    pub fn add_coordinate<C>(&mut self, coordinate: C)
+    where
+        C: Into<Complex>,
+    {
+        self.coordinates.push(coordinate.into());
+    }
+}
+
+/// Smith chart, equivalent to the PGFPlots `smithchart` environment:
+///
+/// ```text
+/// \begin{smithchart}[SmithChartOptions]
+///     % smith plots
+/// \end{smithchart}
+/// ```
+///
+/// The `smithchart` PGFPlots library is loaded automatically.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::tikzpicture::{
+///     smithchart::{Complex, InputMode, SmithChart, SmithPlot},
+///     TikzPicture,
+/// };
+///
+/// // Measured impedances, normalized to 50 Ω.
+/// let load = SmithPlot::new(InputMode::Impedance)
+///     .coordinates([Complex::new(0.5, 0.2), Complex::new(1.0, -0.5)]);
+/// // Measured reflection coefficients.
+/// let s11 = SmithPlot::new(InputMode::ReflectionCoefficient).coordinates([(0.1, 0.3), (-0.2, 0.4)]);
+/// let chart = SmithChart::new().plot(load).plot(s11);
+/// let picture = TikzPicture::from(chart);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SmithChart {
+    options: Vec<SmithChartOption>,
+    plots: Vec<SmithPlot>,
+}
+
+impl fmt::Display for SmithChart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\\begin{{smithchart}}")?;
+        if !self.options.is_empty() {
+            writeln!(f, "[")?;
+            for option in self.options.iter() {
+                writeln!(f, "\t{option},")?;
+            }
+            write!(f, "]")?;
+        }
+        writeln!(f)?;
+
+        for plot in self.plots.iter() {
+            writeln!(f, "{plot}")?;
+        }
+
+        write!(f, "\\end{{smithchart}}")
+    }
+}
+
+impl From<SmithPlot> for SmithChart {
+    fn from(plot: SmithPlot) -> Self {
+        Self::new().plot(plot)
+    }
+}
+
+impl SmithChart {
+    /// Creates a new, empty Smith chart environment.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add an option to control the appearance of the chart. This will overwrite any previous
+    /// mutually exclusive option.
+    pub fn option(mut self, option: SmithChartOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Add a [`SmithPlot`] to the chart.
+    pub fn plot(mut self, plot: SmithPlot) -> Self {
+        self.add_plot(plot);
+        self
+    }
+
+    /// Add an option to control the appearance of the chart. This will overwrite any previous
+    /// mutually exclusive option.
+    pub fn add_option(&mut self, option: SmithChartOption) {
+        match option {
+            SmithChartOption::Custom(_) | SmithChartOption::Style(_) => (),
+            _ => {
+                if let Some(index) = self
+                    .options
+                    .iter()
+                    .position(|idx| std::mem::discriminant(idx) == std::mem::discriminant(&option))
+                {
+                    self.options.remove(index);
+                }
+            }
+        }
+        self.options.push(option);
+    }
+
+    /// Add a [`SmithPlot`] to the chart.
+    pub fn add_plot(&mut self, plot: SmithPlot) {
+        self.plots.push(plot);
+    }
+
+    /// Returns a vector of [`PgfPlotsLib`]s required by the chart.
+    pub fn required_libs(&self) -> Vec<PgfPlotsLib> {
+        vec![PgfPlotsLib::SmithChart]
+    }
+
+    /// Returns the minimum PGFPlots compatibility layer required by the options of the
+    /// contained plots.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        self.plots
+            .iter()
+            .flat_map(|plot| &plot.options)
+            .map(PlotOption::required_pgfcompat)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn smithchart() {
+        let chart = SmithChart::new()
+            .option(SmithChartOption::RealTicks(vec![0.5, 1.0].into()))
+            .plot(SmithPlot::new(InputMode::Impedance).coordinates([Complex::new(0.5, 0.2)]))
+            .plot(
+                SmithPlot::new(InputMode::ReflectionCoefficient)
+                    .option(PlotOption::Custom("red".to_string()))
+                    .coordinates([(0.1, -0.3)]),
+            );
+        assert_eq!(
+            r#"\begin{smithchart}[
+	xtick={0.5, 1},
+]
+	\addplot[] coordinates {
+		(0.5,0.2)
+	};
+	\addplot[
+		is smithchart cs,
+		red,
+	] coordinates {
+		(0.1,-0.3)
+	};
+\end{smithchart}"#,
+            chart.to_string()
+        );
+        assert_eq!(vec![PgfPlotsLib::SmithChart], chart.required_libs());
+    }
+}
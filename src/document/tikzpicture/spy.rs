@@ -0,0 +1,306 @@
+use super::{
+    path::{Path, PathCommand, Point},
+    Anchor,
+};
+use crate::document::length::Length;
+use std::fmt;
+
+/// Shape of the lens of a [`SpyScope`], i.e. of both the magnified region and the
+/// magnifying node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LensShape {
+    #[default]
+    Circle,
+    Rectangle,
+}
+
+impl fmt::Display for LensShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Circle => "circle",
+            Self::Rectangle => "rectangle",
+        })
+    }
+}
+
+/// Settings shared by all the [`Spy`]s of a [`super::TikzPicture`], written as the
+/// `spy using outlines` picture option:
+///
+/// ```text
+/// spy using outlines={circle, magnification=2, size=2cm, connect spies}
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpyScope {
+    shape: LensShape,
+    magnification: f64,
+    /// Size of the magnifying node.
+    size: Length,
+    connect: bool,
+}
+
+impl Default for SpyScope {
+    fn default() -> Self {
+        Self {
+            shape: LensShape::Circle,
+            magnification: 2.0,
+            size: Length::Cm(2.0),
+            connect: true,
+        }
+    }
+}
+
+impl fmt::Display for SpyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "spy using outlines={{{}, magnification={}, size={}",
+            self.shape, self.magnification, self.size
+        )?;
+        if self.connect {
+            write!(f, ", connect spies")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl SpyScope {
+    /// Creates new spy settings with the given `magnification`, a circular lens of `2cm`
+    /// and connection lines between the magnified region and the magnifying node.
+    pub fn new(magnification: f64) -> Self {
+        Self {
+            magnification,
+            ..Default::default()
+        }
+    }
+
+    /// Set the [`LensShape`].
+    pub fn shape(mut self, shape: LensShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Set the size of the magnifying node.
+    pub fn size(mut self, size: Length) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set whether the magnified region and the magnifying node are connected by lines.
+    pub fn connect(mut self, connect: bool) -> Self {
+        self.connect = connect;
+        self
+    }
+}
+
+/// Magnification of the region around the axis coordinate `on`, drawn in a node centered on
+/// the axis coordinate `at`.
+///
+/// Spies are added to an [`super::axis::Axis`] with [`super::axis::Axis::spy`]: the axis
+/// defines the named coordinates `({axis name} spy {n} on)` and `({axis name} spy {n} at)`,
+/// and the [`super::TikzPicture`] writes the `\spy` commands after the axis:
+///
+/// ```text
+/// \spy on (main spy 1 on) in node at (main spy 1 at);
+/// ```
+///
+/// Unnamed axes with spies are named after their position in the picture, e.g. `axis 2` for
+/// the second environment of the picture (see [`super::axis::Axis::name`]). The coordinates
+/// of the spies of a [`super::groupplot::GroupPlot`] panel are prefixed by the panel name,
+/// e.g. `{group name} c2r1`, the group being named after its position in the picture unless
+/// a [`super::groupplot::GroupStyleOption::GroupName`] is set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spy {
+    /// Center of the magnified region, in axis coordinates.
+    pub on: (f64, f64),
+    /// Center of the magnifying node, in axis coordinates.
+    pub at: (f64, f64),
+}
+
+impl Spy {
+    /// Creates a new spy magnifying the region around `on` into a node centered on `at`,
+    /// both in axis coordinates.
+    pub fn new(on: (f64, f64), at: (f64, f64)) -> Self {
+        Self { on, at }
+    }
+
+    /// Returns the paths defining the named coordinates of the `n`-th spy of the axis
+    /// named `axis`, drawn inside the axis.
+    pub(crate) fn coordinates(&self, axis: &str, n: usize) -> [Path; 2] {
+        let (on, at) = (self.on, self.at);
+        [
+            Path::new(PathCommand::Path)
+                .move_to(Point::Axis(on.0, on.1))
+                .coordinate(&format!("{axis} spy {n} on")),
+            Path::new(PathCommand::Path)
+                .move_to(Point::Axis(at.0, at.1))
+                .coordinate(&format!("{axis} spy {n} at")),
+        ]
+    }
+
+    /// Writes the `\spy` command of the `n`-th spy of the axis named `axis`, drawn after the
+    /// axis.
+    pub(crate) fn fmt_command(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        axis: &str,
+        n: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "\\spy on ({axis} spy {n} on) in node at ({axis} spy {n} at);"
+        )
+    }
+}
+
+/// Zoomed inset: the data of an axis in the given region, re-rendered in a smaller axis
+/// placed in a corner of the original one, with lines connecting the region to the inset.
+///
+/// Unlike a [`Spy`], which magnifies the rendered picture, the inset has its own ticks and
+/// is drawn at full resolution. See [`super::TikzPicture::zoom_inset`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZoomInset {
+    /// Region limits, in axis coordinates.
+    pub(crate) x: (f64, f64),
+    pub(crate) y: (f64, f64),
+    pub(crate) corner: Anchor,
+    /// Distance from the borders of the original axis.
+    pub(crate) padding: Length,
+    /// Width and height of the inset.
+    pub(crate) size: Length,
+}
+
+impl ZoomInset {
+    /// Creates a new inset of the region `x.0 <= x <= x.1`, `y.0 <= y <= y.1` placed in the
+    /// north east corner of the axis, `5pt` away from its borders, `3cm` wide and high.
+    pub fn new(x: (f64, f64), y: (f64, f64)) -> Self {
+        Self {
+            x,
+            y,
+            corner: Anchor::NorthEast,
+            padding: Length::Pt(5.0),
+            size: Length::Cm(3.0),
+        }
+    }
+
+    /// Set the corner of the axis in which the inset is placed.
+    pub fn corner(mut self, corner: Anchor) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Set the distance of the inset from the borders of the axis.
+    pub fn padding(mut self, padding: Length) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the width and height of the inset.
+    pub fn size(mut self, size: Length) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::document::{
+        preamble::TikzLib,
+        tikzpicture::{
+            axis::{
+                legend::{LegendImage, LegendPos},
+                plot::bidimensional::Plot2D,
+                Axis, AxisOption,
+            },
+            groupplot::GroupPlot,
+            TikzPicture,
+        },
+    };
+
+    #[test]
+    fn spy() {
+        let picture = TikzPicture::from(Axis::new().spy(Spy::new((1.0, 2.0), (3.0, 4.0))));
+        assert_eq!(
+            r#"\begin{tikzpicture}[
+	spy using outlines={circle, magnification=2, size=2cm, connect spies},
+]
+\begin{axis}[
+	name=axis 1,
+]
+	\path (axis cs:1,2) coordinate (axis 1 spy 1 on);
+	\path (axis cs:3,4) coordinate (axis 1 spy 1 at);
+\end{axis}
+\spy on (axis 1 spy 1 on) in node at (axis 1 spy 1 at);
+\end{tikzpicture}"#,
+            picture.to_string()
+        );
+        assert_eq!(vec![TikzLib::Spy], picture.required_tikzlibs());
+
+        let mut picture = TikzPicture::new();
+        picture.add_axis(Axis::new().spy(Spy::new((1.0, 2.0), (3.0, 4.0))));
+        picture.add_env(
+            GroupPlot::new(2, 1)
+                .cell(Axis::new())
+                .unwrap()
+                .cell(Axis::new().spy(Spy::new((1.0, 2.0), (3.0, 4.0))))
+                .unwrap()
+                .into(),
+        );
+        let picture = picture.to_string();
+        assert!(picture.contains("\\spy on (axis 1 spy 1 on) in node at (axis 1 spy 1 at);"));
+        assert!(picture.contains("\\path (axis cs:1,2) coordinate (axis 2 c2r1 spy 1 on);"));
+        assert!(picture.ends_with(
+            "\\end{groupplot}\n\\spy on (axis 2 c2r1 spy 1 on) in node at (axis 2 c2r1 spy 1 at);\n\\end{tikzpicture}"
+        ));
+        // Panels keep the names set by the `groupplots` library.
+        let group = &picture[picture.find("\\begin{groupplot}").unwrap()..];
+        assert!(!group.contains("\tname="));
+    }
+
+    #[test]
+    fn zoom_inset() {
+        let picture = TikzPicture::new().zoom_inset(
+            Axis::from(Plot2D::new().legend("data"))
+                .plot(LegendImage::new("red", "threshold").into())
+                .option(AxisOption::LegendPos(LegendPos::NorthWest))
+                .title("Signal"),
+            ZoomInset::new((1.0, 2.0), (3.0, 4.0)).size(Length::Cm(2.0)),
+        );
+        assert_eq!(
+            r#"\begin{tikzpicture}
+\begin{axis}[
+	legend pos=north west,
+	title={Signal},
+	name=axis 1,
+]
+	\addplot[] coordinates {
+	};
+	\addlegendentry{data}
+	\addlegendimage{red}
+	\addlegendentry{threshold}
+\draw (axis cs:1,3) rectangle (axis cs:2,4) (axis cs:1,4) coordinate (axis 1 zoom north west) (axis cs:2,3) coordinate (axis 1 zoom south east);
+\end{axis}
+\begin{axis}[
+	name=axis 1 zoom,
+	at={(axis 1.north east)},
+	anchor=north east,
+	xshift=-5pt,
+	yshift=-5pt,
+	xmin={1},
+	xmax={2},
+	ymin={3},
+	ymax={4},
+	width=2cm,
+	height=2cm,
+	scale only axis,
+	axis background/.style={fill=white},
+]
+	\addplot[] coordinates {
+	};
+\end{axis}
+\draw (axis 1 zoom north west) -- (axis 1 zoom.north west) (axis 1 zoom south east) -- (axis 1 zoom.south east);
+\end{tikzpicture}"#,
+            picture.to_string()
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use super::axis::{plot::bidimensional::PlotOption, Grid};
+use crate::{
+    document::preamble::{PgfPlotsCompat, PgfPlotsLib, StyleRef},
+    error::CoordinateError,
+};
+use std::fmt;
+
+/// Relative tolerance used when checking that the components sum to the expected total.
+const SUM_TOLERANCE: f64 = 1e-9;
+
+/// PGFPlots options passed to the [`TernaryAxis`] environment.
+///
+/// The [`TernaryAxisOption::Custom`] variant is provided to add unimplemented keys and will be
+/// written verbatim in the options of the [`TernaryAxis`] environment.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum TernaryAxisOption {
+    /// Custom key-value pairs that have not been implemented.
+    Custom(String),
+    /// Control the label of the first component axis.
+    XLabel(String),
+    /// Control the label of the second component axis.
+    YLabel(String),
+    /// Control the label of the third component axis.
+    ZLabel(String),
+    /// Control the grid lines.
+    Grid(Grid),
+    /// Control the number of minor ticks between major ticks, used by [`Grid::Minor`] and
+    /// [`Grid::Both`].
+    MinorTickNum(usize),
+    /// Control the title of the axis environment.
+    Title(String),
+    /// Apply a named style registered in the document preamble. Unlike the other
+    /// variants, multiple styles can be applied to the same axis.
+    Style(StyleRef),
+}
+
+impl fmt::Display for TernaryAxisOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(option) => write!(f, "{option}"),
+            Self::XLabel(value) => write!(f, "xlabel={{{value}}}"),
+            Self::YLabel(value) => write!(f, "ylabel={{{value}}}"),
+            Self::ZLabel(value) => write!(f, "zlabel={{{value}}}"),
+            Self::Grid(value) => write!(f, "grid={value}"),
+            Self::MinorTickNum(value) => write!(f, "minor tick num={value}"),
+            Self::Title(value) => write!(f, "title={{{value}}}"),
+            Self::Style(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for TernaryAxisOption {
+    fn from(option: &str) -> Self {
+        Self::Custom(option.to_string())
+    }
+}
+
+/// Coordinate in a [`TernaryPlot`], made of three components.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TernaryCoordinate {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl fmt::Display for TernaryCoordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{},{})", self.a, self.b, self.c)
+    }
+}
+
+impl From<(f64, f64, f64)> for TernaryCoordinate {
+    /// Conversion from an `(a, b, c)` tuple into a ternary coordinate.
+    fn from((a, b, c): (f64, f64, f64)) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl TernaryCoordinate {
+    /// Creates a new ternary coordinate.
+    pub fn new(a: f64, b: f64, c: f64) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns the sum of the components.
+    pub fn sum(&self) -> f64 {
+        self.a + self.b + self.c
+    }
+
+    /// Returns the coordinate scaled so that its components sum to `total`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoordinateError::TernaryComponents`] if a component is negative or not finite,
+    /// or if all the components are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::document::tikzpicture::ternary::TernaryCoordinate;
+    ///
+    /// let coordinate = TernaryCoordinate::new(1.0, 1.0, 2.0).normalized(100.0).unwrap();
+    /// assert_eq!(TernaryCoordinate::new(25.0, 25.0, 50.0), coordinate);
+    /// ```
+    pub fn normalized(&self, total: f64) -> Result<Self, CoordinateError> {
+        self.check_components()?;
+        let scale = total / self.sum();
+        Ok(Self::new(self.a * scale, self.b * scale, self.c * scale))
+    }
+
+    /// Checks that the components are finite, non-negative and sum to `total`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoordinateError::TernaryComponents`] on invalid components and
+    /// [`CoordinateError::TernarySum`] if they do not sum to `total`.
+    pub fn check(&self, total: f64) -> Result<(), CoordinateError> {
+        self.check_components()?;
+        if (self.sum() - total).abs() > SUM_TOLERANCE * total.abs().max(1.0) {
+            return Err(CoordinateError::TernarySum {
+                components: (self.a, self.b, self.c),
+                total,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the components are finite, non-negative and not all zero.
+    fn check_components(&self) -> Result<(), CoordinateError> {
+        let components = [self.a, self.b, self.c];
+        if components.iter().any(|x| !x.is_finite() || *x < 0.0) || self.sum() == 0.0 {
+            return Err(CoordinateError::TernaryComponents {
+                components: (self.a, self.b, self.c),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Plot inside a [`TernaryAxis`].
+///
+/// ```text
+/// \addplot3[PlotOptions] coordinates {
+///     (a,b,c)
+/// };
+/// ```
+///
+/// Coordinates are written as fractions of the total of the containing [`TernaryAxis`].
+#[derive(Clone, Debug, Default)]
+pub struct TernaryPlot {
+    options: Vec<PlotOption>,
+    coordinates: Vec<TernaryCoordinate>,
+}
+
+impl TernaryPlot {
+    /// Creates a new, empty ternary plot.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add an option to control the appearance of the plot, e.g. its color with a
+    /// [`PlotOption::Custom`] or a [`PlotOption::Style`].
+    pub fn option(mut self, option: PlotOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Set the plot coordinates.
+    pub fn coordinates<C>(mut self, coordinates: C) -> Self
+    where
+        C: IntoIterator,
+        C::Item: Into<TernaryCoordinate>,
+    {
+        self.coordinates = coordinates.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add an option to control the appearance of the plot.
+    pub fn add_option(&mut self, option: PlotOption) {
+        self.options.push(option);
+    }
+
+    /// Pushes plot coordinate.
+    pub fn add_coordinate<C>(&mut self, coordinate: C)
+    where
+        C: Into<TernaryCoordinate>,
+    {
+        self.coordinates.push(coordinate.into());
+    }
+
+    /// Writes the plot, each coordinate divided by `total` or, if `None`, by the sum of its
+    /// components.
+    fn fmt_fractions(&self, f: &mut fmt::Formatter<'_>, total: Option<f64>) -> fmt::Result {
+        write!(f, "\t\\addplot3[")?;
+        if !self.options.is_empty() {
+            writeln!(f)?;
+            for option in self.options.iter() {
+                writeln!(f, "\t\t{option},")?;
+            }
+            write!(f, "\t")?;
+        }
+        writeln!(f, "] coordinates {{")?;
+
+        for coordinate in self.coordinates.iter() {
+            let scale = total.unwrap_or_else(|| coordinate.sum());
+            writeln!(
+                f,
+                "\t\t{}",
+                TernaryCoordinate::new(
+                    coordinate.a / scale,
+                    coordinate.b / scale,
+                    coordinate.c / scale
+                )
+            )?;
+        }
+
+        write!(f, "\t}};")
+    }
+}
+
+/// Ternary diagram, equivalent to the PGFPlots `ternaryaxis` environment:
+///
+/// ```text
+/// \begin{ternaryaxis}[TernaryAxisOptions]
+///     % ternary plots
+/// \end{ternaryaxis}
+/// ```
+///
+/// Coordinate components must sum to the axis total (`1` by default, see
+/// [`TernaryAxis::total`]), unless the axis normalizes them (see [`TernaryAxis::normalize`]).
+/// [`crate::document::Document::pdf`] checks the coordinates before compilation. The `ternary`
+/// PGFPlots library is loaded automatically.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::tikzpicture::{
+///     axis::Grid,
+///     ternary::{TernaryAxis, TernaryAxisOption, TernaryPlot},
+///     TikzPicture,
+/// };
+///
+/// // Phase compositions, in percent.
+/// let phase = TernaryPlot::new().coordinates([(20.0, 30.0, 50.0), (40.0, 40.0, 20.0)]);
+/// let axis = TernaryAxis::new()
+///     .total(100.0)
+///     .option(TernaryAxisOption::XLabel("Cu".to_string()))
+///     .option(TernaryAxisOption::YLabel("Sn".to_string()))
+///     .option(TernaryAxisOption::ZLabel("Zn".to_string()))
+///     .option(TernaryAxisOption::Grid(Grid::Major))
+///     .plot(phase);
+/// assert!(axis.check().is_ok());
+/// let picture = TikzPicture::from(axis);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TernaryAxis {
+    options: Vec<TernaryAxisOption>,
+    plots: Vec<TernaryPlot>,
+    /// Expected sum of the coordinate components.
+    total: f64,
+    /// Whether coordinates are normalized instead of checked against `total`.
+    normalize: bool,
+}
+
+impl Default for TernaryAxis {
+    fn default() -> Self {
+        Self {
+            options: vec![],
+            plots: vec![],
+            total: 1.0,
+            normalize: false,
+        }
+    }
+}
+
+impl fmt::Display for TernaryAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\\begin{{ternaryaxis}}")?;
+        if !self.options.is_empty() {
+            writeln!(f, "[")?;
+            for option in self.options.iter() {
+                writeln!(f, "\t{option},")?;
+            }
+            write!(f, "]")?;
+        }
+        writeln!(f)?;
+
+        let total = (!self.normalize).then_some(self.total);
+        for plot in self.plots.iter() {
+            plot.fmt_fractions(f, total)?;
+            writeln!(f)?;
+        }
+
+        write!(f, "\\end{{ternaryaxis}}")
+    }
+}
+
+impl From<TernaryPlot> for TernaryAxis {
+    fn from(plot: TernaryPlot) -> Self {
+        Self::new().plot(plot)
+    }
+}
+
+impl TernaryAxis {
+    /// Creates a new, empty ternary axis environment.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add an option to control the appearance of the axis. This will overwrite any previous
+    /// mutually exclusive option.
+    pub fn option(mut self, option: TernaryAxisOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Add a [`TernaryPlot`] to the axis.
+    pub fn plot(mut self, plot: TernaryPlot) -> Self {
+        self.add_plot(plot);
+        self
+    }
+
+    /// Set the expected sum of the coordinate components, e.g. `100` for percentages.
+    pub fn total(mut self, total: f64) -> Self {
+        self.set_total(total);
+        self
+    }
+
+    /// Normalize the coordinates, so that components of any positive sum are accepted.
+    pub fn normalize(mut self) -> Self {
+        self.set_normalize(true);
+        self
+    }
+
+    /// Add an option to control the appearance of the axis. This will overwrite any previous
+    /// mutually exclusive option.
+    pub fn add_option(&mut self, option: TernaryAxisOption) {
+        match option {
+            TernaryAxisOption::Custom(_) | TernaryAxisOption::Style(_) => (),
+            _ => {
+                if let Some(index) = self
+                    .options
+                    .iter()
+                    .position(|idx| std::mem::discriminant(idx) == std::mem::discriminant(&option))
+                {
+                    self.options.remove(index);
+                }
+            }
+        }
+        self.options.push(option);
+    }
+
+    /// Add a [`TernaryPlot`] to the axis.
+    pub fn add_plot(&mut self, plot: TernaryPlot) {
+        self.plots.push(plot);
+    }
+
+    /// Set the expected sum of the coordinate components.
+    pub fn set_total(&mut self, total: f64) {
+        self.total = total;
+    }
+
+    /// Set whether the coordinates are normalized instead of checked against the total.
+    pub fn set_normalize(&mut self, normalize: bool) {
+        self.normalize = normalize;
+    }
+
+    /// Checks the coordinates of all the plots.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CoordinateError`] for the first coordinate with invalid components or, unless
+    /// the axis normalizes the coordinates, not summing to the axis total.
+    pub fn check(&self) -> Result<(), CoordinateError> {
+        for coordinate in self.plots.iter().flat_map(|plot| plot.coordinates.iter()) {
+            if self.normalize {
+                coordinate.check_components()?;
+            } else {
+                coordinate.check(self.total)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a vector of [`PgfPlotsLib`]s required by the axis.
+    pub fn required_libs(&self) -> Vec<PgfPlotsLib> {
+        vec![PgfPlotsLib::Ternary]
+    }
+
+    /// Returns the minimum PGFPlots compatibility layer required by the options of the
+    /// contained plots.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        self.plots
+            .iter()
+            .flat_map(|plot| &plot.options)
+            .map(PlotOption::required_pgfcompat)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ternary_axis() {
+        let plot = TernaryPlot::new().coordinates([(20.0, 30.0, 50.0)]);
+        let axis = TernaryAxis::from(plot.clone())
+            .total(100.0)
+            .option(TernaryAxisOption::Grid(Grid::Minor))
+            .option(TernaryAxisOption::Grid(Grid::Both))
+            .option(TernaryAxisOption::ZLabel("C".to_string()));
+        assert_eq!(Ok(()), axis.check());
+        assert_eq!(
+            r#"\begin{ternaryaxis}[
+	grid=both,
+	zlabel={C},
+]
+	\addplot3[] coordinates {
+		(0.2,0.3,0.5)
+	};
+\end{ternaryaxis}"#,
+            axis.to_string()
+        );
+
+        let axis = TernaryAxis::from(plot.clone());
+        assert_eq!(
+            Err(CoordinateError::TernarySum {
+                components: (20.0, 30.0, 50.0),
+                total: 1.0
+            }),
+            axis.check()
+        );
+        assert_eq!(Ok(()), axis.clone().normalize().check());
+
+        let axis = TernaryAxis::from(TernaryPlot::new().coordinates([(0.0, 0.0, 0.0)])).normalize();
+        assert_eq!(
+            Err(CoordinateError::TernaryComponents {
+                components: (0.0, 0.0, 0.0)
+            }),
+            axis.check()
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use super::axis::{plot::Plot, Axis, AxisKind, AxisOption, AxisXLine, AxisYLine, Scale};
+use crate::{
+    document::preamble::{PgfPlotsCompat, PgfPlotsLib, TikzLib},
+    error::{CoordinateError, LayoutError},
+};
+use itertools::Itertools;
+use std::fmt;
+
+/// Two overlaid axes sharing the `x` axis, with the `y` axis of the first one on the left and
+/// the `y` axis of the second one on the right, e.g. to plot two quantities with different
+/// units:
+///
+/// ```text
+/// \begin{axis}[AxisOptions, axis y line*=left]
+///     % left plots
+/// \end{axis}
+/// \begin{axis}[AxisOptions, axis y line*=right, axis x line=none]
+///     % right plots
+/// \end{axis}
+/// ```
+///
+/// The size, `x` limits and scale of the left axis are copied to the right one, and the
+/// plots of the right axis continue the cycle list of the left one. The left axis must set
+/// both `x` limits (see [`TwinAxis::check_layout`]), otherwise each axis would autoscale to
+/// its own data. The legend entries of the plots of both axes (see
+/// [`super::axis::plot::bidimensional::Plot2D::legend`]) are combined into a single legend,
+/// drawn by the right axis: its legend options apply. The styles of the left plots are
+/// referred to through labels, resolved by a second LaTeX pass.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::document::tikzpicture::{
+///     axis::{plot::bidimensional::Plot2D, Axis},
+///     twin::TwinAxis,
+///     TikzPicture,
+/// };
+///
+/// let temperature = Plot2D::new()
+///     .coordinates([(0.0, 20.0).into(), (1.0, 25.0).into()])
+///     .legend("Temperature");
+/// let pressure = Plot2D::new()
+///     .coordinates([(0.0, 1.0).into(), (1.0, 1.2).into()])
+///     .legend("Pressure");
+/// let twin = TwinAxis::new(
+///     Axis::from(temperature).x_min(0.0).x_max(1.0),
+///     Axis::from(pressure),
+/// );
+/// let picture = TikzPicture::from(twin);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TwinAxis {
+    left: Axis,
+    right: Axis,
+    /// Prefix of the labels of the left plots, referred to by the combined legend.
+    name: String,
+}
+
+impl fmt::Display for TwinAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let left_plots = self
+            .left
+            .plots()
+            .iter()
+            .filter(|plot| is_addplot(plot))
+            .count();
+
+        // Legend entries of the left plots are moved to the right axis, referring to the
+        // style of the left plots through their labels.
+        let mut left = self.left.clone();
+        left.add_option(AxisOption::AxisYLineAst(AxisYLine::Left));
+        fmt_begin(f, left.kind(), left.options())?;
+        let mut entries = vec![];
+        for plot in left.plots_mut() {
+            let entry = plot.take_legend();
+            writeln!(f, "{plot}")?;
+            if let Some(entry) = entry {
+                let label = format!("{} plot {}", self.name, entries.len() + 1);
+                writeln!(f, "\t\\label{{{label}}}")?;
+                entries.push((label, entry));
+            }
+        }
+        left.fmt_spy_coordinates(f, left.spy_name())?;
+        writeln!(f, "\\end{{{}}}", left.kind())?;
+
+        let right = self.right_axis(left_plots);
+        fmt_begin(f, right.kind(), right.options())?;
+        for (label, entry) in entries {
+            writeln!(f, "\t\\addlegendimage{{/pgfplots/refstyle={label}}}")?;
+            writeln!(f, "\t\\addlegendentry{{{entry}}}")?;
+        }
+        for plot in right.plots() {
+            writeln!(f, "{plot}")?;
+        }
+        right.fmt_spy_coordinates(f, right.spy_name())?;
+        write!(f, "\\end{{{}}}", right.kind())
+    }
+}
+
+/// Returns whether the plot is drawn with `\addplot`, i.e. has a cycle list style and a
+/// legend entry.
+fn is_addplot(plot: &Plot) -> bool {
+    matches!(plot, Plot::Plot2D(_) | Plot::Histogram(_))
+}
+
+/// Writes the beginning of an axis environment of the given kind.
+fn fmt_begin(f: &mut fmt::Formatter<'_>, kind: AxisKind, options: &[AxisOption]) -> fmt::Result {
+    write!(f, "\\begin{{{kind}}}")?;
+    if !options.is_empty() {
+        writeln!(f, "[")?;
+        for option in options {
+            writeln!(f, "\t{option},")?;
+        }
+        write!(f, "]")?;
+    }
+    writeln!(f)
+}
+
+impl TwinAxis {
+    /// Creates a new twin axis, with the `y` axis of `left` on the left and the `y` axis of
+    /// `right` on the right.
+    pub fn new(left: Axis, right: Axis) -> Self {
+        Self {
+            left,
+            right,
+            name: "twin".to_string(),
+        }
+    }
+
+    /// Set the prefix of the labels of the left plots, which must be unique in the document
+    /// (`twin` by default).
+    pub fn name(mut self, name: &str) -> Self {
+        self.set_name(name);
+        self
+    }
+
+    /// Set the prefix of the labels of the left plots, which must be unique in the document.
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    /// Names the axes with spies but no name `{name} left` and `{name} right`.
+    pub(crate) fn name_spied(&mut self, name: &str) {
+        self.left.name_spied(&format!("{name} left"));
+        self.right.name_spied(&format!("{name} right"));
+    }
+
+    /// Returns the left and right axes.
+    pub(crate) fn axes(&self) -> [&Axis; 2] {
+        [&self.left, &self.right]
+    }
+
+    /// Returns the right axis, synchronized with the left one which contains `left_plots`
+    /// plots.
+    fn right_axis(&self, left_plots: usize) -> Axis {
+        let mut right = self.right.clone();
+        right.add_option(AxisOption::AxisYLineAst(AxisYLine::Right));
+        right.add_option(AxisOption::AxisXLine(AxisXLine::None));
+        if self.left.kind().log_x() {
+            right.add_option(AxisOption::XMode(Scale::Log));
+        }
+        for option in self.left.options() {
+            if matches!(
+                option,
+                AxisOption::XMin(_)
+                    | AxisOption::XMax(_)
+                    | AxisOption::XMode(_)
+                    | AxisOption::Width(_)
+                    | AxisOption::Height(_)
+                    | AxisOption::ScaleOnlyAxis
+            ) {
+                right.add_option(option.clone());
+            }
+        }
+        if left_plots > 0 {
+            right.add_option(AxisOption::Custom(format!("cycle list shift={left_plots}")));
+        }
+
+        right
+    }
+
+    /// Returns a vector of [`PgfPlotsLib`]s required by both axes, each appearing once.
+    pub fn required_libs(&self) -> Vec<PgfPlotsLib> {
+        self.left
+            .required_libs()
+            .into_iter()
+            .chain(self.right.required_libs())
+            .unique()
+            .collect()
+    }
+
+    /// Returns the minimum PGFPlots compatibility layer required by both axes.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        self.left
+            .required_pgfcompat()
+            .max(self.right.required_pgfcompat())
+    }
+
+    /// Returns a vector of [`TikzLib`]s required by both axes, each appearing once.
+    pub fn required_tikzlibs(&self) -> Vec<TikzLib> {
+        self.left
+            .required_tikzlibs()
+            .into_iter()
+            .chain(self.right.required_tikzlibs())
+            .unique()
+            .collect()
+    }
+
+    /// Returns whether the legend refers to the left plots through labels, which are only
+    /// resolved by a second LaTeX pass.
+    pub(crate) fn has_references(&self) -> bool {
+        self.left.plots().iter().any(Plot::has_legend)
+    }
+
+    /// Checks that the left axis sets both `x` limits, shared by the right axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LayoutError::TwinLimits`] if the left axis misses an `x` limit.
+    pub fn check_layout(&self) -> Result<(), LayoutError> {
+        let options = self.left.options();
+        if !options
+            .iter()
+            .any(|option| matches!(option, AxisOption::XMin(_)))
+            || !options
+                .iter()
+                .any(|option| matches!(option, AxisOption::XMax(_)))
+        {
+            return Err(LayoutError::TwinLimits);
+        }
+        Ok(())
+    }
+
+    /// Checks that the coordinates of both axes are strictly positive on their logarithmic
+    /// dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoordinateError::NonPositiveLog`] for the first offending coordinate.
+    pub fn check(&self) -> Result<(), CoordinateError> {
+        self.left.check()?;
+        let (_, log_y) = self.right.log_dimensions();
+        self.right.check_log(self.left.log_dimensions().0, log_y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::document::tikzpicture::axis::plot::bidimensional::Plot2D;
+
+    #[test]
+    fn twin_axis() {
+        let twin = TwinAxis::new(
+            Axis::from(Plot2D::new().legend("A")).x_min(0.0).x_max(1.0),
+            Axis::from(Plot2D::new().legend("B")).x_max(2.0).y_min(0.0),
+        );
+        assert_eq!(
+            r#"\begin{axis}[
+	xmin={0},
+	xmax={1},
+	axis y line*=left,
+]
+	\addplot[] coordinates {
+	};
+	\label{twin plot 1}
+\end{axis}
+\begin{axis}[
+	ymin={0},
+	axis y line*=right,
+	axis x line=none,
+	xmin={0},
+	xmax={1},
+	cycle list shift=1,
+]
+	\addlegendimage{/pgfplots/refstyle=twin plot 1}
+	\addlegendentry{A}
+	\addplot[] coordinates {
+	};
+	\addlegendentry{B}
+\end{axis}"#,
+            twin.to_string()
+        );
+        assert_eq!(Ok(()), twin.check_layout());
+        assert_eq!(Ok(()), twin.check());
+        assert!(twin.has_references());
+
+        let twin = TwinAxis::new(Axis::new().x_min(0.0), Axis::new().x_max(1.0));
+        assert_eq!(Err(LayoutError::TwinLimits), twin.check_layout());
+        assert_eq!(Ok(()), twin.check());
+        assert!(!twin.has_references());
+    }
+}
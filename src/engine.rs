@@ -67,6 +67,33 @@ impl FromStr for LatexEngine {
 }
 
 impl LatexEngine {
+    /// Returns whether the [`LatexEngine`] natively supports Unicode input and OpenType fonts.
+    pub(crate) fn is_unicode(&self) -> bool {
+        match self {
+            LatexEngine::PdfLatex => false,
+            LatexEngine::LuaLatex => true,
+            #[cfg(feature = "tectonic")]
+            LatexEngine::Tectonic => true,
+        }
+    }
+
+    /// Returns the preamble commands making the engine output reproducible, i.e. suppressing
+    /// the PDF trailer ID and the information depending on the build environment (e.g. the
+    /// input file path).
+    ///
+    /// Creation and modification dates are pinned separately, through the
+    /// `SOURCE_DATE_EPOCH` environment variable.
+    pub(crate) fn reproducible_commands(&self) -> Vec<&'static str> {
+        match self {
+            LatexEngine::PdfLatex => vec!["\\pdftrailerid{}", "\\pdfsuppressptexinfo=-1"],
+            // Suppress `PTEX.FullBanner` (1), `PTEX.FileName` (2) and the trailer ID (512).
+            LatexEngine::LuaLatex => vec!["\\pdfvariable suppressoptionalinfo 515"],
+            // Tectonic output is made reproducible by its deterministic mode.
+            #[cfg(feature = "tectonic")]
+            LatexEngine::Tectonic => vec![],
+        }
+    }
+
     /// Returns [`LatexEngine`] CLI arguments.
     pub(crate) fn args(&self) -> [&str; 2] {
         match self {
@@ -12,6 +12,9 @@ pub enum PgfPlotsError {
     Show(opener::OpenError),
     Save(LatexOutputSaveError),
     Compat(PgfPlotsCompatError),
+    Preamble(PreambleError),
+    Coordinate(CoordinateError),
+    Layout(LayoutError),
 }
 
 impl fmt::Display for PgfPlotsError {
@@ -21,6 +24,9 @@ impl fmt::Display for PgfPlotsError {
             Self::Show(error) => write!(f, "unalbe to open produced output: {error}"),
             Self::Save(error) => write!(f, "unable to save produced output: {error}"),
             Self::Compat(error) => write!(f, "compat version: {error}"),
+            Self::Preamble(error) => write!(f, "preamble: {error}"),
+            Self::Coordinate(error) => write!(f, "coordinates: {error}"),
+            Self::Layout(error) => write!(f, "layout: {error}"),
         }
     }
 }
@@ -45,12 +51,166 @@ impl From<PgfPlotsCompatError> for PgfPlotsError {
     }
 }
 
+impl From<PreambleError> for PgfPlotsError {
+    fn from(error: PreambleError) -> Self {
+        Self::Preamble(error)
+    }
+}
+
+impl From<CoordinateError> for PgfPlotsError {
+    fn from(error: CoordinateError) -> Self {
+        Self::Coordinate(error)
+    }
+}
+
+impl From<LayoutError> for PgfPlotsError {
+    fn from(error: LayoutError) -> Self {
+        Self::Layout(error)
+    }
+}
+
 impl From<LatexOutputSaveError> for PgfPlotsError {
     fn from(error: LatexOutputSaveError) -> Self {
         Self::Save(error)
     }
 }
 
+/// The error type returned when the [`crate::document::preamble::Preamble`] dependencies
+/// can not be resolved.
+#[derive(Debug, Clone)]
+pub enum PreambleError {
+    /// The same package has been loaded with conflicting options.
+    ConflictingOptions {
+        /// LaTeX package name.
+        package: String,
+        /// Option already set.
+        option: String,
+        /// Option conflicting with the one already set.
+        conflicting: String,
+    },
+    /// A macro has been defined with more than 9 arguments.
+    MacroArity {
+        /// Macro name.
+        name: String,
+        /// Number of arguments.
+        arity: u8,
+    },
+}
+
+impl fmt::Display for PreambleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictingOptions {
+                package,
+                option,
+                conflicting,
+            } => write!(
+                f,
+                "package `{package}` loaded with conflicting options `{option}` and `{conflicting}`"
+            ),
+            Self::MacroArity { name, arity } => write!(
+                f,
+                "macro `\\{name}` defined with {arity} arguments; at most 9 are allowed"
+            ),
+        }
+    }
+}
+
+impl error::Error for PreambleError {}
+
+/// The error type returned when plot coordinates can not be drawn on their axis.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoordinateError {
+    /// The components of a ternary coordinate do not sum to the expected total.
+    TernarySum {
+        /// Coordinate components.
+        components: (f64, f64, f64),
+        /// Expected total.
+        total: f64,
+    },
+    /// A ternary coordinate has a negative or non-finite component, or all components are zero.
+    TernaryComponents {
+        /// Coordinate components.
+        components: (f64, f64, f64),
+    },
+    /// A coordinate is not strictly positive on a logarithmic axis.
+    NonPositiveLog {
+        /// Logarithmic dimension (`'x'` or `'y'`).
+        dimension: char,
+        /// Coordinate `(x, y)`.
+        coordinate: (f64, f64),
+    },
+}
+
+impl fmt::Display for CoordinateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TernarySum {
+                components: (a, b, c),
+                total,
+            } => write!(
+                f,
+                "ternary coordinate `({a},{b},{c})` components sum to {}, expected {total}",
+                a + b + c
+            ),
+            Self::TernaryComponents {
+                components: (a, b, c),
+            } => write!(
+                f,
+                "ternary coordinate `({a},{b},{c})` components must be finite, non-negative and not all zero"
+            ),
+            Self::NonPositiveLog {
+                dimension,
+                coordinate: (x, y),
+            } => write!(
+                f,
+                "coordinate `({x},{y})` must have a strictly positive `{dimension}` on a logarithmic `{dimension}` axis"
+            ),
+        }
+    }
+}
+
+impl error::Error for CoordinateError {}
+
+/// The error type returned when the axes of an environment can not be laid out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutError {
+    /// A group plot has more cells than its grid can hold.
+    GroupCells {
+        /// Number of cells, including the empty ones.
+        cells: usize,
+        /// Number of columns of the grid.
+        columns: usize,
+        /// Number of rows of the grid.
+        rows: usize,
+    },
+    /// The left axis of a twin axis does not set both `x` limits, which the right axis must
+    /// share instead of autoscaling independently.
+    TwinLimits,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GroupCells {
+                cells,
+                columns,
+                rows,
+            } => write!(
+                f,
+                "group plot has {cells} cells, but its {columns} by {rows} grid holds at most {}",
+                columns * rows
+            ),
+            Self::TwinLimits => write!(
+                f,
+                "the left axis of a twin axis must set both `xmin` and `xmax`, shared by the right axis"
+            ),
+        }
+    }
+}
+
+impl error::Error for LayoutError {}
+
 /// The error type returned when a [`Picture`] fails to compile into a PDF.
 #[derive(Debug)]
 pub enum CompileError {
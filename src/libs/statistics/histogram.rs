@@ -1,4 +1,7 @@
-use crate::document::tikzpicture::axis::plot::bidimensional::PlotOption;
+use crate::document::{
+    preamble::PgfPlotsCompat,
+    tikzpicture::axis::{legend, plot::bidimensional::PlotOption},
+};
 use std::fmt;
 
 // FIXME
@@ -123,6 +126,8 @@ pub struct Histogram {
     hist_options: Vec<HistogramOption>,
     /// Histogram data.
     pub data: Vec<f64>, // TODO: what if one wants to pass data in a file?
+    /// Legend entry, written as `\addlegendentry{...}` after the plot.
+    legend: Option<String>,
 }
 
 impl<D> From<D> for Histogram
@@ -131,9 +136,8 @@ where
 {
     fn from(data: D) -> Self {
         Self {
-            options: vec![],
-            hist_options: vec![],
             data: data.into(),
+            ..Default::default()
         }
     }
 }
@@ -165,7 +169,7 @@ impl Histogram {
     /// ```
     pub fn option(mut self, option: PlotOption) -> Self {
         match option {
-            PlotOption::Custom(_) => (),
+            PlotOption::Custom(_) | PlotOption::Style(_) => (),
             _ => {
                 if let Some(index) = self
                     .options
@@ -269,7 +273,7 @@ impl Histogram {
     /// ```
     pub fn add_option(&mut self, option: PlotOption) {
         match option {
-            PlotOption::Custom(_) => (),
+            PlotOption::Custom(_) | PlotOption::Style(_) => (),
             _ => {
                 if let Some(index) = self
                     .options
@@ -310,6 +314,15 @@ impl Histogram {
         self.hist_options.push(option);
     }
 
+    /// Returns the minimum PGFPlots compatibility layer required by the plot options.
+    pub fn required_pgfcompat(&self) -> PgfPlotsCompat {
+        self.options
+            .iter()
+            .map(PlotOption::required_pgfcompat)
+            .max()
+            .unwrap_or_default()
+    }
+
     /// Sets the number `num` of equally sized bins.
     ///
     /// Convenience method for:
@@ -353,6 +366,27 @@ impl Histogram {
     pub fn set_normalize(&mut self) {
         self.add_hist_option(HistogramOption::Density(true));
     }
+
+    /// Set the legend entry of the histogram (chaining version).
+    pub fn legend(mut self, entry: &str) -> Self {
+        self.set_legend(entry);
+        self
+    }
+
+    /// Set the legend entry of the histogram.
+    pub fn set_legend(&mut self, entry: &str) {
+        self.legend = Some(entry.to_string());
+    }
+
+    /// Removes and returns the legend entry of the histogram.
+    pub(crate) fn take_legend(&mut self) -> Option<String> {
+        self.legend.take()
+    }
+
+    /// Returns whether the histogram has a legend entry.
+    pub(crate) fn has_legend(&self) -> bool {
+        self.legend.is_some()
+    }
 }
 
 impl fmt::Display for Histogram {
@@ -384,6 +418,7 @@ impl fmt::Display for Histogram {
         }
 
         write!(f, "\t}};")?;
+        legend::fmt_entry(f, self.legend.as_deref())?;
 
         Ok(())
     }
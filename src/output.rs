@@ -73,66 +73,38 @@ impl LatexOutputType {
     }
 }
 
-/// [`LatexOutput`] factory.
-struct LatexOutputBuilder<'a, 'b, 's> {
-    /// LaTeX output type.
-    output_type: LatexOutputType,
-    /// Output file stem.
-    file_stem: &'s str,
-    /// Custom temporary output directory:
-    /// if [`None`] it will be automatically set.
-    dir: Option<TmpBuilder<'a, 'b>>,
+/// Layout of the [`LatexOutput`] of a document containing multiple pictures.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// All pictures in a single page.
+    #[default]
+    SinglePage,
+    /// One page per picture, in a single output file (with the `standalone` document class,
+    /// each page is cropped to its picture using the `multi=tikzpicture` class option).
+    PagePerPicture,
+    /// One output file per picture, named `{stem}-{n}.pdf` where `n` is the (1-based) index
+    /// of the picture in the document.
+    FilePerPicture,
 }
 
-impl<'a, 'b, 's> Default for LatexOutputBuilder<'a, 'b, 's> {
-    fn default() -> Self {
-        Self {
-            output_type: Default::default(),
-            file_stem: "pgfplot",
-            dir: None,
-        }
-    }
+/// Page of a [`LatexOutput`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputPage {
+    /// Output file containing the page.
+    path: PathBuf,
+    /// Page number (1-based) in the output file.
+    number: usize,
 }
 
-impl<'a, 'b, 's> LatexOutputBuilder<'a, 'b, 's> {
-    /// Sets the [`LatexOutput`]'s [`LatexOutputType`].
-    fn output_type(&mut self, output_type: LatexOutputType) -> &mut Self {
-        self.output_type = output_type;
-        self
-    }
-
-    /// Sets the [`LatexOutput`]'s file stem.
-    fn file_stem<S>(&mut self, file_stem: S) -> &mut Self
-    where
-        S: AsRef<&'s str>,
-    {
-        self.file_stem = file_stem.as_ref();
-        self
-    }
-
-    /// Sets the [`LatexOutput`]'s directory.
-    fn dir(&mut self, dir: TmpBuilder<'a, 'b>) -> &mut Self {
-        self.dir = Some(dir);
-        self
+impl OutputPage {
+    /// Returns the path of the output file containing the page.
+    pub fn path(&self) -> &Path {
+        &self.path
     }
 
-    /// Builds the [`LatexOutput`], consuming the [`LatexOutputBuilder`].
-    fn build(self) -> Result<LatexOutput> {
-        let dir = self
-            .dir
-            .unwrap_or({
-                let mut builder = TmpBuilder::new();
-                builder.prefix("pgfplot");
-                builder
-            })
-            .tempdir()
-            .map_err(CompileError::TempDir)?;
-
-        Ok(LatexOutput {
-            output_type: self.output_type,
-            tex_file: dir.path().join(format!("{}.tex", self.file_stem)),
-            dir,
-        })
+    /// Returns the page number (1-based) in the output file.
+    pub fn number(&self) -> usize {
+        self.number
     }
 }
 
@@ -141,22 +113,38 @@ impl<'a, 'b, 's> LatexOutputBuilder<'a, 'b, 's> {
 pub struct LatexOutput {
     /// Output type.
     output_type: LatexOutputType,
-    /// Main Tex file.
-    tex_file: PathBuf,
+    /// Tex files stem.
+    file_stem: String,
+    /// Compiled Tex files, one per output file.
+    tex_files: Vec<PathBuf>,
+    /// Number of pages of each output file, read from the engine log.
+    pages: Vec<usize>,
+    /// Number of times the engine is run on each source.
+    passes: usize,
+    /// Pinned build date, as seconds since the Unix epoch: if [`None`] the current date is
+    /// used.
+    source_date_epoch: Option<u64>,
     /// Ouptut temporary directory.
     dir: TempDir,
 }
 
 impl LatexOutput {
-    /// Retuns a [`LatexOutputBuilder`] factory.
-    #[inline]
-    fn builder<'a, 'b, 's>() -> LatexOutputBuilder<'a, 'b, 's> {
-        LatexOutputBuilder::default()
-    }
-
-    /// Constructs a new [`LatexOutput`] using defaults.
+    /// Constructs a new [`LatexOutput`] in a new temporary directory.
     pub(crate) fn new() -> Result<Self> {
-        Self::builder().build()
+        let dir = TmpBuilder::new()
+            .prefix("pgfplot")
+            .tempdir()
+            .map_err(CompileError::TempDir)?;
+
+        Ok(Self {
+            output_type: LatexOutputType::default(),
+            file_stem: "pgfplot".to_string(),
+            tex_files: vec![],
+            pages: vec![],
+            passes: 1,
+            source_date_epoch: None,
+            dir,
+        })
     }
 
     /// Retuns a reference to [`LatexOutput`]'s directory path.
@@ -165,20 +153,58 @@ impl LatexOutput {
         self.dir.path()
     }
 
-    /// Returns a reference to main Tex [`LatexOutput`]'s file pathbuf.
-    #[inline]
-    fn tex_file_path(&self) -> &Path {
-        self.tex_file.as_path()
+    /// Returns the produced output file path (the first one, if multiple output files have
+    /// been produced).
+    pub(crate) fn output_path(&self) -> PathBuf {
+        self.output_paths().into_iter().next().unwrap_or_else(|| {
+            self.dir_path()
+                .join(&self.file_stem)
+                .with_extension(self.output_type.ext())
+        })
     }
 
-    /// Returns a reference to the produced output file path.
-    pub(crate) fn output_path(&self) -> PathBuf {
-        self.tex_file.with_extension(self.output_type.ext())
+    /// Pins the build date (as seconds since the Unix epoch) embedded by the engine in the
+    /// output, for the output to be reproducible.
+    pub(crate) fn set_source_date_epoch(&mut self, source_date_epoch: u64) {
+        self.source_date_epoch = Some(source_date_epoch);
+    }
+
+    /// Sets the number of times the engine is run on each source, e.g. `2` to resolve
+    /// references to labels.
+    pub(crate) fn set_passes(&mut self, passes: usize) {
+        self.passes = passes;
+    }
+
+    /// Returns the produced output file paths, in picture order.
+    pub fn output_paths(&self) -> Vec<PathBuf> {
+        self.tex_files
+            .iter()
+            .map(|tex_file| tex_file.with_extension(self.output_type.ext()))
+            .collect()
+    }
+
+    /// Returns the produced pages, in picture order.
+    pub fn pages(&self) -> Vec<OutputPage> {
+        self.output_paths()
+            .into_iter()
+            .zip(&self.pages)
+            .flat_map(|(path, &pages)| {
+                (1..=pages).map(move |number| OutputPage {
+                    path: path.clone(),
+                    number,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the total number of produced pages.
+    pub fn page_count(&self) -> usize {
+        self.pages.iter().sum()
     }
 
     /// Compile LaTeX output using Tectonic engine.
     #[cfg(feature = "tectonic")]
-    fn compile_tectonic(&self) -> Result<(), CompileError> {
+    fn compile_tectonic(&self, tex_file: &Path) -> Result<(), CompileError> {
         // Modified from `tectonic::latex_to_pdf` to generate the files
         // instead of just returning the bytes.
 
@@ -203,8 +229,8 @@ impl LatexOutput {
 
         let mut sb = tectonic::driver::ProcessingSessionBuilder::default();
         sb.bundle(bundle)
-            .primary_input_path(self.tex_file_path())
-            .tex_input_name(self.tex_file_path().file_name().unwrap())
+            .primary_input_path(tex_file)
+            .tex_input_name(tex_file.file_name().unwrap())
             .format_name("latex")
             .format_cache_path(format_cache_path)
             .keep_logs(true) // Just to keep the behaviour consistent with `pdflatex`
@@ -212,6 +238,10 @@ impl LatexOutput {
             .print_stdout(false)
             .output_format(tectonic::driver::OutputFormat::Pdf)
             .output_dir(self.dir_path());
+        if let Some(epoch) = self.source_date_epoch {
+            sb.build_date(std::time::UNIX_EPOCH + std::time::Duration::from_secs(epoch))
+                .deterministic_mode(true);
+        }
 
         let mut sess = tectonic::ctry!(
             sb.create(&mut status);
@@ -223,51 +253,134 @@ impl LatexOutput {
         );
     }
 
-    /// Compile LaTeX output.
+    /// Compile LaTeX output: each source produces a separate output file.
+    ///
+    /// The number of pages of each file is read from the engine log, falling back to
+    /// `expected_pages` if the log can not be read.
+    ///
+    /// With [`OutputMode::FilePerPicture`] the sources are compiled to `{stem}-{n}.tex`, `n`
+    /// being the (1-based) index of the source, even if there is a single one. Otherwise the
+    /// single source is compiled to `{stem}.tex`.
     pub(crate) fn compile<S>(
-        &self,
+        &mut self,
         engine: LatexEngine,
-        source: S,
+        sources: &[S],
+        mode: OutputMode,
+        expected_pages: usize,
     ) -> result::Result<(), CompileError>
     where
         S: AsRef<str>,
     {
+        self.tex_files = self.tex_files(sources.len(), mode);
+
+        let mut pages = vec![];
+        for (tex_file, source) in self.tex_files.iter().zip(sources) {
+            self.compile_file(engine, tex_file, source.as_ref())?;
+            let log = fs::read(tex_file.with_extension("log")).unwrap_or_default();
+            pages.push(written_pages(&String::from_utf8_lossy(&log)).unwrap_or(expected_pages));
+        }
+        self.pages = pages;
+
+        Ok(())
+    }
+
+    /// Returns the paths of the `count` `.tex` files written according to the [`OutputMode`].
+    fn tex_files(&self, count: usize, mode: OutputMode) -> Vec<PathBuf> {
+        match mode {
+            OutputMode::FilePerPicture => (1..=count)
+                .map(|n| self.dir_path().join(format!("{}-{n}.tex", self.file_stem)))
+                .collect(),
+            OutputMode::SinglePage | OutputMode::PagePerPicture => {
+                vec![self.dir_path().join(format!("{}.tex", self.file_stem))]
+            }
+        }
+    }
+
+    /// Compile a single LaTeX `source` written to `tex_file`.
+    fn compile_file(
+        &self,
+        engine: LatexEngine,
+        tex_file: &Path,
+        source: &str,
+    ) -> result::Result<(), CompileError> {
+        fs::File::create(tex_file)?.write_all(source.as_bytes())?;
+
         #[cfg(feature = "tectonic")]
         if engine == LatexEngine::Tectonic {
-            return self.compile_tectonic();
+            // Tectonic reruns the engine itself until the references are resolved.
+            return self.compile_tectonic(tex_file);
         }
 
-        fs::File::create(self.tex_file_path())?.write_all(source.as_ref().as_bytes())?;
-
-        let exit_status = Command::new(engine.to_string())
+        let mut command = Command::new(engine.to_string());
+        command
             .current_dir(self.dir_path())
             .stdout(Stdio::null())
             .stderr(Stdio::null())
             .args(engine.args())
-            .arg(self.tex_file_path())
-            .status()?;
-
-        if !exit_status.success() {
-            return Err(CompileError::BadExitStatus {
-                engine,
-                exit_status,
-            });
+            .arg(tex_file);
+        if let Some(epoch) = self.source_date_epoch {
+            // Pin both the creation date and the date returned by `\today`.
+            command
+                .env("SOURCE_DATE_EPOCH", epoch.to_string())
+                .env("FORCE_SOURCE_DATE", "1");
+        }
+
+        for _ in 0..self.passes {
+            let exit_status = command.status()?;
+
+            if !exit_status.success() {
+                return Err(CompileError::BadExitStatus {
+                    engine,
+                    exit_status,
+                });
+            }
         }
 
         Ok(())
     }
 
     /// Saves LaTeX output to specified `path` and returns whether the file has been saved.
+    ///
+    /// If multiple output files have been produced, only the first one is saved: see
+    /// [`LatexOutput::save_all`].
     pub fn save<P, F>(&self, path: P, overwrite: F) -> Result<bool>
     where
         P: AsRef<Path>,
         F: FnOnce() -> Result<bool>,
     {
-        let path = path.as_ref();
-        let output_path = self.output_path();
+        Self::save_file(&self.output_path(), path.as_ref(), overwrite)
+    }
 
+    /// Saves all the produced output files to the `dir` directory (created if it does not
+    /// exist) and returns the paths of the saved files.
+    ///
+    /// The `overwrite` closure is called with the path of each file already existing in `dir`.
+    pub fn save_all<P, F>(&self, dir: P, mut overwrite: F) -> Result<Vec<PathBuf>>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path) -> Result<bool>,
+    {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(LatexOutputSaveError::CreateDestDir)?;
+
+        let mut saved = Vec::new();
+        for output_path in self.output_paths() {
+            let path = dir.join(output_path.file_name().unwrap());
+            if Self::save_file(&output_path, &path, || overwrite(&path))? {
+                saved.push(path);
+            }
+        }
+
+        Ok(saved)
+    }
+
+    /// Saves the `output_path` file to `path` and returns whether the file has been saved.
+    fn save_file<F>(output_path: &Path, path: &Path, overwrite: F) -> Result<bool>
+    where
+        F: FnOnce() -> Result<bool>,
+    {
         let copy = |path: &Path| -> Result<bool> {
-            fs::copy(&output_path, path)
+            fs::copy(output_path, path)
                 .map_err(|error| LatexOutputSaveError::SaveFail(path.to_path_buf(), error))?;
 
             Ok(true)
@@ -319,3 +432,48 @@ impl LatexOutput {
         Ok(())
     }
 }
+
+/// Returns the number of pages written by the engine, as reported at the end of its `log`
+/// (e.g. `Output written on pgfplot.pdf (2 pages, 12345 bytes).`).
+fn written_pages(log: &str) -> Option<usize> {
+    if log.contains("No pages of output.") {
+        return Some(0);
+    }
+    // The log wraps long lines, possibly in the middle of the reported count.
+    let written = log
+        .rsplit_once("Output written on ")?
+        .1
+        .replace(['\r', '\n'], "");
+    let (_, count) = written.rsplit_once(" (")?;
+    count.split_once(" page")?.0.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tex_files() {
+        let output = LatexOutput::new().unwrap();
+        let single = output.tex_files(1, OutputMode::PagePerPicture);
+        assert_eq!(vec![output.dir_path().join("pgfplot.tex")], single);
+        let numbered = output.tex_files(1, OutputMode::FilePerPicture);
+        assert_eq!(vec![output.dir_path().join("pgfplot-1.tex")], numbered);
+    }
+
+    #[test]
+    fn written_pages() {
+        assert_eq!(
+            Some(3),
+            super::written_pages(
+                "Output written on /tmp/pgfplotAbC123/pgfplot.pdf (3 pages, 4\n5678 bytes).\n"
+            )
+        );
+        assert_eq!(
+            Some(1),
+            super::written_pages("Output written on pgfplot.xdv (1 page, 1234 bytes).")
+        );
+        assert_eq!(Some(0), super::written_pages("No pages of output.\n"));
+        assert_eq!(None, super::written_pages(""));
+    }
+}